@@ -0,0 +1,37 @@
+use crate::{Answer, Solution};
+
+pub use rand::rngs::StdRng;
+pub use rand::seq::SliceRandom;
+pub use rand::{Rng, SeedableRng};
+
+/// A day that can make up random puzzle inputs. Generated inputs must parse, and must keep the
+/// promises the real inputs make (a guard that walks off the board, rules without cycles, ...).
+pub trait Generate {
+    fn generate(rng: &mut StdRng) -> String;
+}
+
+/// A reproducible RNG for `seed`.
+pub fn seeded(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Solves `cases` generated inputs for `S` and compares both parts with `reference`, a slow but
+/// obviously correct solver working from the raw input. Panics with the seed and input of the
+/// first disagreement.
+pub fn check_against_reference<S: Solution + Generate>(
+    cases: u64,
+    reference: impl Fn(&str) -> (Answer, Answer),
+) {
+    for seed in 0..cases {
+        let input = S::generate(&mut seeded(seed));
+        let parsed = S::parse(&input)
+            .unwrap_or_else(|err| panic!("seed {} generated an invalid input: {}", seed, err));
+        let expected = reference(&input);
+        let actual = (S::part1(&parsed), S::part2(&parsed));
+        assert_eq!(
+            actual, expected,
+            "seed {} disagrees with the reference (left: solver, right: reference) on:\n{}",
+            seed, input
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{numbered_lines, ParseResult, Span};
+use crate::Result;
+
+/// A cell position, counted from the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+}
+
+/// One of the eight compass directions, with rows growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// All eight directions, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The `(row, col)` step this direction takes.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::UpRight => (-1, 1),
+            Direction::Right => (0, 1),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (1, 0),
+            Direction::DownLeft => (1, -1),
+            Direction::Left => (0, -1),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    fn index(self) -> usize {
+        Direction::ALL.iter().position(|&d| d == self).unwrap()
+    }
+
+    /// Rotates 90° clockwise.
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self.index() + 2) % 8]
+    }
+
+    /// Rotates 90° anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self.index() + 6) % 8]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self.index() + 4) % 8]
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            return Err(format!(
+                "row {} has {} cells but row 1 has {}",
+                row + 1,
+                rows[row].len(),
+                width
+            )
+            .into());
+        }
+
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            height,
+            width,
+        })
+    }
+
+    /// Parses one row per line, turning each character into a cell with `parse_cell`. Cells it
+    /// rejects are reported as not being `expected`. Trailing blank lines are ignored.
+    pub fn parse_with(
+        text: &str,
+        expected: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<Self> {
+        let mut lines: Vec<Span> = numbered_lines(text).collect();
+        while lines.last().is_some_and(|line| line.is_blank()) {
+            lines.pop();
+        }
+
+        let width = lines.first().map_or(0, |line| line.text.chars().count());
+        let mut cells = Vec::new();
+        for line in &lines {
+            let mut row_width = 0;
+            for (c, span) in line.chars() {
+                if row_width == width {
+                    return Err(span.error("end of line"));
+                }
+                cells.push(parse_cell(c).ok_or_else(|| span.error(expected))?);
+                row_width += 1;
+            }
+            if row_width < width {
+                return Err(line.end().error(format!("a row of {} cells", width)));
+            }
+        }
+
+        Ok(Self {
+            cells,
+            height: lines.len(),
+            width,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn is_in_bounds(&self, row: isize, col: isize) -> bool {
+        row >= 0 && col >= 0 && row < self.height as isize && col < self.width as isize
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        if coord.row < self.height && coord.col < self.width {
+            Some(&self.cells[coord.row * self.width + coord.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        if coord.row < self.height && coord.col < self.width {
+            Some(&mut self.cells[coord.row * self.width + coord.col])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `coord` by an arbitrary `(row, col)` step, or `None` if that leaves the grid.
+    pub fn offset_by(&self, coord: Coord, row_step: isize, col_step: isize) -> Option<Coord> {
+        let row = coord.row as isize + row_step;
+        let col = coord.col as isize + col_step;
+        self.is_in_bounds(row, col)
+            .then(|| Coord::new(row as usize, col as usize))
+    }
+
+    /// Moves `coord` one cell in `direction`, or `None` if that leaves the grid.
+    pub fn offset(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        let (row_step, col_step) = direction.delta();
+        self.offset_by(coord, row_step, col_step)
+    }
+
+    /// The up to four orthogonal neighbours of `coord` that lie inside the grid.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.offset(coord, direction))
+    }
+
+    /// The up to eight neighbours of `coord`, diagonals included, that lie inside the grid.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(coord, direction))
+    }
+
+    /// Every coordinate, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Coord::new(row, col)))
+    }
+
+    /// Every cell with its coordinate, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    /// The coordinates of every cell that satisfies `predicate`.
+    pub fn positions(&self, mut predicate: impl FnMut(&T) -> bool) -> Vec<Coord> {
+        self.iter()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(coord, _)| coord)
+            .collect()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |row| &self[Coord::new(row, col)])
+    }
+
+    /// Walks from `start` (inclusive) in `direction` until it leaves the grid.
+    pub fn ray(&self, start: Coord, direction: Direction) -> impl Iterator<Item = Coord> + '_ {
+        std::iter::successors(self.get(start).map(|_| start), move |&coord| {
+            self.offset(coord, direction)
+        })
+    }
+
+    /// Every full line through the grid in `direction`: rows for `Right`, columns for `Down`,
+    /// diagonals for `DownRight` and `DownLeft`, and the reverse of each for the opposites.
+    pub fn lines(&self, direction: Direction) -> impl Iterator<Item = Vec<Coord>> + '_ {
+        self.coords()
+            .filter(move |&coord| self.offset(coord, direction.opposite()).is_none())
+            .map(move |start| self.ray(start, direction).collect())
+    }
+}
+
+impl Grid<char> {
+    /// Parses one row per line, keeping every character as-is.
+    pub fn parse(text: &str) -> ParseResult<Self> {
+        Self::parse_with(text, "any character", Some)
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The coordinates of every cell equal to `value`.
+    pub fn find_all(&self, value: &T) -> Vec<Coord> {
+        self.positions(|cell| cell == value)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `height` × `width` grid with every cell set to `value`.
+    pub fn new(height: usize, width: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; height * width],
+            height,
+            width,
+        }
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coord))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        let grid = Grid::parse("ab\ncd\n").unwrap();
+
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid[Coord::new(1, 0)], 'c');
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+    }
+
+    #[test]
+    fn test_parse_rejects_ragged_rows() {
+        assert!(Grid::parse("abc\nde").is_err());
+    }
+
+    #[test]
+    fn test_parse_with_reports_position() {
+        let err = Grid::parse_with("12\n3x\n\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.to_string(), "2:2: expected a digit, found \"x\"");
+    }
+
+    #[test]
+    fn test_neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours4(Coord::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Coord::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Coord::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_lines_cover_every_diagonal() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        let diagonals: Vec<String> = grid
+            .lines(Direction::DownRight)
+            .map(|line| line.iter().map(|&coord| grid[coord]).collect())
+            .collect();
+
+        assert_eq!(diagonals, vec!["ae", "bf", "c", "d"]);
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+    }
+}
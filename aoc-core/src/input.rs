@@ -0,0 +1,27 @@
+use std::fs;
+use std::io::{self, Read};
+
+use crate::Result;
+
+/// Splits text into a grid of characters, one row per line.
+pub fn parse_grid(contents: &str) -> Vec<Vec<char>> {
+    contents
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect()
+}
+
+/// Reads a puzzle input file into a grid of characters.
+pub fn load_grid_from_file(file_name: &str) -> Result<Vec<Vec<char>>> {
+    Ok(parse_grid(&fs::read_to_string(file_name)?))
+}
+
+/// Reads a whole puzzle input, treating `-` as stdin.
+pub fn read_input(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        return Ok(contents);
+    }
+    fs::read_to_string(path)
+}
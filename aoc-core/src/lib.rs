@@ -0,0 +1,15 @@
+//! Shared helpers for the day-N puzzle crates.
+
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod solution;
+pub mod timing;
+
+pub use generate::Generate;
+pub use grid::{Coord, Direction, Grid};
+pub use input::{load_grid_from_file, parse_grid, read_input};
+pub use parse::{numbered_lines, ParseError, ParseResult, Span};
+pub use solution::{bench_parts, run_parts, Answer, Result, Solution};
+pub use timing::{sample, time, Stats};
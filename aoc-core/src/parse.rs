@@ -0,0 +1,218 @@
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Result of parsing puzzle input.
+pub type ParseResult<T> = std::result::Result<T, ParseError>;
+
+/// Why a puzzle input could not be parsed, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The input file, once the caller knows it.
+    pub file: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// How many characters from `column` the diagnostic underlines.
+    pub width: usize,
+    /// What the parser was looking for, e.g. `a number`.
+    pub expected: String,
+    /// What it found instead, e.g. `"12a"` or `end of line`.
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            width: 1,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Records which file the input came from.
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+
+    /// Renders the error with the offending line of `source` and a marker under the column.
+    pub fn diagnostic(&self, source: &str) -> String {
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("{}:{}", self.line, self.column),
+        };
+        let mut out = format!(
+            "error: expected {}, found {}\n --> {}\n",
+            self.expected, self.found, location
+        );
+
+        if let Some(text) = source.lines().nth(self.line.saturating_sub(1)) {
+            let gutter = " ".repeat(self.line.to_string().len());
+            out += &format!("{} |\n", gutter);
+            out += &format!("{} | {}\n", self.line, text);
+            out += &format!(
+                "{} | {}{}\n",
+                gutter,
+                " ".repeat(self.column.saturating_sub(1)),
+                "^".repeat(self.width.max(1))
+            );
+        }
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A piece of one input line that remembers where it came from, so errors can point at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+/// Every line of `input` as a span, numbered from 1. CRLF endings are stripped.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    input.lines().enumerate().map(|(index, text)| Span {
+        line: index + 1,
+        column: 1,
+        text,
+    })
+}
+
+impl<'a> Span<'a> {
+    /// The part of this span between two byte offsets.
+    fn slice(&self, start: usize, end: usize) -> Span<'a> {
+        Span {
+            line: self.line,
+            column: self.column + self.text[..start].chars().count(),
+            text: &self.text[start..end],
+        }
+    }
+
+    /// The empty span just past the end of this one.
+    pub fn end(&self) -> Span<'a> {
+        self.slice(self.text.len(), self.text.len())
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+        self.slice(start, end)
+    }
+
+    /// An error saying `expected` was wanted where this span is.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = if self.text.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("{:?}", self.text)
+        };
+        ParseError {
+            width: self.text.chars().count(),
+            ..ParseError::new(self.line, self.column, expected, found)
+        }
+    }
+
+    /// Parses the whole span, blaming it as not being `expected` on failure.
+    pub fn parse<T: FromStr>(&self, expected: &str) -> ParseResult<T> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    /// The whitespace-separated fields in this span.
+    pub fn fields(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        let text = self.text;
+        text.split_whitespace().map(move |field| {
+            let start = field.as_ptr() as usize - text.as_ptr() as usize;
+            self.slice(start, start + field.len())
+        })
+    }
+
+    /// The pieces between each `separator`.
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> + '_ {
+        let text = self.text;
+        text.split(separator).map(move |piece| {
+            let start = piece.as_ptr() as usize - text.as_ptr() as usize;
+            self.slice(start, start + piece.len())
+        })
+    }
+
+    /// Splits around the first `separator`, which must be present.
+    pub fn split_once(&self, separator: &str) -> ParseResult<(Span<'a>, Span<'a>)> {
+        let start = self
+            .text
+            .find(separator)
+            .ok_or_else(|| self.end().error(format!("{:?}", separator)))?;
+        Ok((
+            self.slice(0, start),
+            self.slice(start + separator.len(), self.text.len()),
+        ))
+    }
+
+    /// Each character with the single-character span it sits in.
+    pub fn chars(&self) -> impl Iterator<Item = (char, Span<'a>)> + '_ {
+        self.text
+            .char_indices()
+            .map(move |(start, c)| (c, self.slice(start, start + c.len_utf8())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields_keep_their_columns() {
+        let line = numbered_lines("a\n  12   x4").nth(1).unwrap();
+        let err = line
+            .fields()
+            .map(|field| field.parse::<i32>("a number"))
+            .collect::<ParseResult<Vec<i32>>>()
+            .unwrap_err();
+
+        assert_eq!(err, {
+            let mut expected = ParseError::new(2, 8, "a number", "\"x4\"");
+            expected.width = 2;
+            expected
+        });
+    }
+
+    #[test]
+    fn test_diagnostic_points_at_column() {
+        let source = "1 2\n3 x\n";
+        let err = ParseError::new(2, 3, "a number", "\"x\"").with_file("input.txt");
+
+        assert_eq!(
+            err.diagnostic(source),
+            "error: expected a number, found \"x\"\n --> input.txt:2:3\n  |\n2 | 3 x\n  |   ^\n"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use crate::parse::ParseResult;
+use crate::time;
+use crate::timing::{sample, Stats};
+
+/// Boxed error for failures that have no position in the input.
+pub type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+/// A puzzle answer, as handed back by a solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(number: i32) -> Self {
+        Answer::Number(number.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(number: i64) -> Self {
+        Answer::Number(number)
+    }
+}
+
+/// Totals too big for an `i64` are kept as their digits.
+impl From<i128> for Answer {
+    fn from(number: i128) -> Self {
+        match i64::try_from(number) {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(number.to_string()),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        Answer::Number(number as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+/// One day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// Parses `input` once and times each requested part (1 or 2) against it.
+pub fn run_parts<S: Solution>(input: &str, parts: &[u8]) -> ParseResult<Vec<(Answer, Duration)>> {
+    let parsed = S::parse(input)?;
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            1 => time(|| S::part1(&parsed)),
+            _ => time(|| S::part2(&parsed)),
+        })
+        .collect())
+}
+
+/// Times parsing and both parts separately, each with `warmup` untimed runs first.
+pub fn bench_parts<S: Solution>(
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> ParseResult<[Stats; 3]> {
+    let parsed = S::parse(input)?;
+    Ok([
+        sample(warmup, iterations, || S::parse(input)),
+        sample(warmup, iterations, || S::part1(&parsed)),
+        sample(warmup, iterations, || S::part2(&parsed)),
+    ])
+}
@@ -0,0 +1,57 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Runs `f` once and returns its result along with how long it took.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Summary of repeated timings of the same piece of work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarises a non-empty set of samples, sorting them in place.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort();
+        let p95_index = (samples.len() * 95).div_ceil(100) - 1;
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            p95: samples[p95_index],
+        }
+    }
+}
+
+/// Runs `f` `warmup` times without timing it, then times it `iterations` more times.
+pub fn sample<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+    let mut samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| time(|| black_box(f())).1)
+        .collect();
+    Stats::from_samples(&mut samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(&mut samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(11));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+    }
+}
@@ -0,0 +1,58 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// One known-good answer: the day, the part and the input it was produced from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedAnswer {
+    pub day: u8,
+    pub part: u8,
+    /// Input path, relative to the workspace root.
+    pub input: String,
+    pub answer: String,
+}
+
+/// The checked-in `answers.toml`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AnswerStore {
+    #[serde(default, rename = "answer")]
+    pub answers: Vec<RecordedAnswer>,
+}
+
+impl AnswerStore {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+        toml::from_str(&contents).map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answer_store() {
+        let store: AnswerStore = toml::from_str(
+            r#"
+            [[answer]]
+            day = 9
+            part = 2
+            input = "day-9/src/example.txt"
+            answer = "2858"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            store.answers,
+            vec![RecordedAnswer {
+                day: 9,
+                part: 2,
+                input: "day-9/src/example.txt".to_string(),
+                answer: "2858".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc_core::Stats;
+use serde::{Deserialize, Serialize};
+
+use crate::days;
+
+const STEPS: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Timings for one step of one day, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub day: u8,
+    pub step: String,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl BenchResult {
+    fn new(day: u8, step: &str, stats: Stats) -> Self {
+        Self {
+            day,
+            step: step.to_string(),
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            p95_ns: stats.p95.as_nanos() as u64,
+        }
+    }
+}
+
+/// A full `aoc bench` run, as written to and read back from JSON.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub warmup: usize,
+    pub iterations: usize,
+    pub results: Vec<BenchResult>,
+}
+
+impl BenchReport {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+        serde_json::from_str(&contents)
+            .map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|err| err.to_string())?;
+        fs::write(path, json + "\n")
+            .map_err(|err| format!("failed to write {}: {}", path.display(), err))
+    }
+
+    /// Results whose median is more than `threshold` (0.1 = 10%) slower than the baseline.
+    pub fn regressions<'a>(
+        &'a self,
+        baseline: &'a BenchReport,
+        threshold: f64,
+    ) -> Vec<(&'a BenchResult, &'a BenchResult)> {
+        self.results
+            .iter()
+            .filter_map(|current| {
+                let previous = baseline.results.iter().find(|previous| {
+                    previous.day == current.day && previous.step == current.step
+                })?;
+                let limit = previous.median_ns as f64 * (1.0 + threshold);
+                (current.median_ns as f64 > limit).then_some((current, previous))
+            })
+            .collect()
+    }
+}
+
+pub struct BenchOptions<'a> {
+    pub day: Option<u8>,
+    pub warmup: usize,
+    pub iterations: usize,
+    pub output: &'a Path,
+    pub baseline: Option<&'a Path>,
+    pub threshold: f64,
+}
+
+/// Benchmarks every solved day (or just `options.day`) against its checked-in input.
+pub fn bench(options: &BenchOptions) -> ExitCode {
+    let baseline = match options.baseline.map(BenchReport::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut report = BenchReport {
+        warmup: options.warmup,
+        iterations: options.iterations,
+        results: Vec::new(),
+    };
+
+    println!(
+        "{:>4}  {:<6} {:>12} {:>12} {:>12}",
+        "day", "step", "min", "median", "p95"
+    );
+    for day in (1..=25).filter(|day| options.day.is_none_or(|only| only == *day)) {
+        let Some(solved) = days::day(day) else {
+            continue;
+        };
+        let path = crate::default_input_path(day);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) => {
+                eprintln!("failed to read {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let stats = match (solved.bench)(&contents, options.warmup, options.iterations) {
+            Ok(stats) => stats,
+            Err(err) => {
+                eprint!("{}", err.with_file(&path).diagnostic(&contents));
+                return ExitCode::FAILURE;
+            }
+        };
+
+        for (step, stats) in STEPS.iter().zip(stats) {
+            println!(
+                "{:>4}  {:<6} {:>12} {:>12} {:>12}",
+                day,
+                step,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95)
+            );
+            report.results.push(BenchResult::new(day, step, stats));
+        }
+    }
+
+    if let Err(err) = report.save(options.output) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+    println!("wrote {}", options.output.display());
+
+    let Some(baseline) = baseline else {
+        return ExitCode::SUCCESS;
+    };
+    let regressions = report.regressions(&baseline, options.threshold);
+    for (current, previous) in &regressions {
+        println!(
+            "day {} {} regressed: median {:.2?} vs baseline {:.2?} ({:+.1}%)",
+            current.day,
+            current.step,
+            Duration::from_nanos(current.median_ns),
+            Duration::from_nanos(previous.median_ns),
+            (current.median_ns as f64 / previous.median_ns as f64 - 1.0) * 100.0
+        );
+    }
+    if regressions.is_empty() {
+        println!("no regressions against the baseline");
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, step: &str, median_ns: u64) -> BenchResult {
+        BenchResult {
+            day,
+            step: step.to_string(),
+            min_ns: median_ns,
+            median_ns,
+            p95_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn test_regressions_past_threshold() {
+        let baseline = BenchReport {
+            results: vec![result(1, "part1", 100), result(1, "part2", 100)],
+            ..Default::default()
+        };
+        let current = BenchReport {
+            results: vec![
+                result(1, "part1", 109),
+                result(1, "part2", 111),
+                result(2, "part1", 500),
+            ],
+            ..Default::default()
+        };
+
+        let regressions = current.regressions(&baseline, 0.1);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].0.step, "part2");
+    }
+}
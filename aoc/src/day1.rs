@@ -0,0 +1,232 @@
+use std::process::ExitCode;
+
+use aoc_core::generate::{seeded, Rng};
+use aoc_core::{ParseError, Stats};
+use clap::builder::PossibleValuesParser;
+use clap::{Subcommand, ValueEnum};
+use day_1::report::Report;
+use day_1::similarity::{metric, METRICS};
+use day_1::{calculate_sum_of_differences, calculate_weighted_sum, dense, Day1};
+
+use crate::{default_input_path, input_label, load, open_input};
+
+#[derive(Subcommand)]
+pub enum Day1Command {
+    /// Solve both parts without loading the lists into memory
+    Stream {
+        /// Input file, or `-` for stdin [default: day-1/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        /// IDs per list to sort in memory before spilling a run to disk
+        #[arg(long, default_value_t = 1_000_000)]
+        run_size: usize,
+    },
+    /// Explain both totals pair by pair
+    Report {
+        /// Input file, or `-` for stdin [default: day-1/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        /// How many entries to list in each top-N table
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+        /// Most buckets the distance histogram is split into
+        #[arg(long, default_value_t = 10)]
+        buckets: usize,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Score how alike the two lists are
+    Similarity {
+        /// Input file, or `-` for stdin [default: day-1/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        /// Only print this metric; every metric is printed when omitted
+        #[arg(long, value_parser = PossibleValuesParser::new(METRICS.map(|metric| metric.name())))]
+        metric: Option<String>,
+    },
+    /// Time the general and the counting-sort paths on a large synthetic input
+    BenchSort {
+        /// Pairs in the synthetic lists
+        #[arg(long, default_value_t = 10_000_000)]
+        lines: usize,
+        /// Seed for the random IDs
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Untimed runs before sampling starts
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+        /// Timed runs per path
+        #[arg(long, default_value_t = 5)]
+        iterations: usize,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+pub fn day1(command: Day1Command) -> ExitCode {
+    match command {
+        Day1Command::Stream { input, run_size } => stream(input, run_size),
+        Day1Command::Report {
+            input,
+            top,
+            buckets,
+            format,
+        } => report(input, top, buckets, format),
+        Day1Command::Similarity { input, metric } => similarity(input, metric),
+        Day1Command::BenchSort {
+            lines,
+            seed,
+            warmup,
+            iterations,
+        } => bench_sort(lines, seed, warmup, iterations),
+    }
+}
+
+fn report(input: Option<String>, top: usize, buckets: usize, format: Format) -> ExitCode {
+    let Some((left, right)) = load::<Day1>(1, input) else {
+        return ExitCode::FAILURE;
+    };
+    let report = Report::new(&left, &right, top, buckets);
+    match format {
+        Format::Text => print!("{}", report),
+        Format::Csv => print!("{}", report.to_csv()),
+        Format::Json => match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(err) => {
+                eprintln!("failed to encode the report: {}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+    ExitCode::SUCCESS
+}
+
+fn similarity(input: Option<String>, name: Option<String>) -> ExitCode {
+    let Some((left, right)) = load::<Day1>(1, input) else {
+        return ExitCode::FAILURE;
+    };
+    let metrics = match name {
+        Some(name) => vec![metric(&name).expect("clap only accepts known metrics")],
+        None => METRICS.to_vec(),
+    };
+    for metric in metrics {
+        println!("{}: {}", metric.name(), metric.score(&left, &right));
+    }
+    ExitCode::SUCCESS
+}
+
+/// Times both parts, and sorting the lists on their own, through the general path, which sorts
+/// with comparisons and counts with a `HashMap`, and through the dense path, which counting-sorts
+/// and counts into a flat array, on `lines` random five-digit pairs.
+fn bench_sort(lines: usize, seed: u64, warmup: usize, iterations: usize) -> ExitCode {
+    let mut rng = seeded(seed);
+    let mut ids = || -> Vec<i64> {
+        (0..lines)
+            .map(|_| rng.random_range(10000..100000))
+            .collect()
+    };
+    let (left, right) = (ids(), ids());
+    let (mut sorted_left, mut sorted_right) = (left.clone(), right.clone());
+    sorted_left.sort_unstable();
+    sorted_right.sort_unstable();
+    let (mut counted_left, mut counted_right) = (left.clone(), right.clone());
+    dense::sort_ids(&mut counted_left);
+    dense::sort_ids(&mut counted_right);
+    if sorted_left != counted_left
+        || sorted_right != counted_right
+        || dense::sum_of_differences(&left, &right)
+            != calculate_sum_of_differences(&sorted_left, &sorted_right)
+        || dense::weighted_sum(&left, &right) != calculate_weighted_sum(&left, &right)
+    {
+        eprintln!("the dense and general paths disagree on seed {}", seed);
+        return ExitCode::FAILURE;
+    }
+
+    let general = [
+        aoc_core::sample(warmup, iterations, || {
+            let mut left = left.clone();
+            let mut right = right.clone();
+            left.sort_unstable();
+            right.sort_unstable();
+            calculate_sum_of_differences(&left, &right)
+        }),
+        aoc_core::sample(warmup, iterations, || calculate_weighted_sum(&left, &right)),
+        aoc_core::sample(warmup, iterations, || {
+            let mut left = left.clone();
+            let mut right = right.clone();
+            left.sort_unstable();
+            right.sort_unstable();
+            (left, right)
+        }),
+    ];
+    let fast = [
+        aoc_core::sample(warmup, iterations, || {
+            dense::sum_of_differences(&left, &right)
+        }),
+        aoc_core::sample(warmup, iterations, || dense::weighted_sum(&left, &right)),
+        aoc_core::sample(warmup, iterations, || {
+            let mut left = left.clone();
+            let mut right = right.clone();
+            dense::sort_ids(&mut left);
+            dense::sort_ids(&mut right);
+            (left, right)
+        }),
+    ];
+
+    println!("{} pairs, {} timed runs each", lines, iterations.max(1));
+    println!(
+        "{:<6} {:>14} {:>14} {:>9}",
+        "part", "general", "dense", "speedup"
+    );
+    for (row, (general, fast)) in ["1", "2", "sort"].iter().zip(general.iter().zip(&fast)) {
+        print_comparison(row, general, fast);
+    }
+    ExitCode::SUCCESS
+}
+
+fn print_comparison(row: &str, general: &Stats, fast: &Stats) {
+    println!(
+        "{:<6} {:>14} {:>14} {:>8.1}x",
+        row,
+        format!("{:?}", general.median),
+        format!("{:?}", fast.median),
+        general.median.as_secs_f64() / fast.median.as_secs_f64().max(f64::EPSILON)
+    );
+}
+
+fn stream(input: Option<String>, run_size: usize) -> ExitCode {
+    let path = input.unwrap_or_else(|| default_input_path(1).display().to_string());
+    let reader = match open_input(&path) {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("failed to read {}: {}", path, err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match day_1::stream::stream_totals(reader, run_size) {
+        Ok((distance, similarity)) => {
+            println!("day 1 part 1: {}", distance);
+            println!("day 1 part 2: {}", similarity);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            match err.downcast_ref::<ParseError>() {
+                // the input has already streamed past, so there is no source line to show
+                Some(err) => {
+                    eprint!(
+                        "{}",
+                        err.clone().with_file(input_label(&path)).diagnostic("")
+                    );
+                }
+                None => eprintln!("failed to stream {}: {}", path, err),
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use std::fs;
+use std::io::BufRead;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc_core::{numbered_lines, ParseResult, Span};
+use clap::{Args, Subcommand};
+use day_2::explain::explain;
+use day_2::monitor::Monitor;
+use day_2::policy::{SafetyPolicy, Trend};
+use day_2::removals::{minimum_removals, without, RemovalSummary};
+use day_2::{parse_report, Day2};
+
+use crate::{input_label, load, load_with, open_input};
+
+#[derive(Subcommand)]
+pub enum Day2Command {
+    /// Count the safe reports under a custom safety policy
+    Check {
+        /// Input file, or `-` for stdin [default: day-2/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        #[command(flatten)]
+        policy: PolicyArgs,
+        /// Classify every report and say which rule it broke
+        #[arg(long)]
+        explain: bool,
+        /// Print the explanations as JSON
+        #[arg(long, requires = "explain")]
+        json: bool,
+    },
+    /// Find the fewest levels to drop from each report to make it safe
+    Removals {
+        /// Input file, or `-` for stdin [default: day-2/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        #[command(flatten)]
+        policy: PolicyArgs,
+    },
+    /// Follow levels as they arrive and alert as soon as a stream turns unsafe. Each line is
+    /// `NAME LEVEL...` to extend a named stream, or `LEVEL...` for a whole report
+    Monitor {
+        /// Where to read from, `-` for stdin
+        #[arg(long, default_value = "-")]
+        input: String,
+        #[command(flatten)]
+        policy: PolicyArgs,
+    },
+}
+
+/// A safety policy from an optional TOML file, with flags overriding its fields. Anything
+/// left unset keeps the puzzle's rules.
+#[derive(Args)]
+pub struct PolicyArgs {
+    /// TOML file setting any of min_step, max_step, allow_plateaus, trend and max_removals
+    #[arg(long)]
+    policy: Option<PathBuf>,
+    /// Smallest allowed change between neighbouring levels [default: 1]
+    #[arg(long)]
+    min_step: Option<i32>,
+    /// Largest allowed change between neighbouring levels [default: 3]
+    #[arg(long)]
+    max_step: Option<i32>,
+    /// Whether neighbouring levels may be equal [default: false]
+    #[arg(long)]
+    allow_plateaus: Option<bool>,
+    /// Required direction: any, increasing or decreasing [default: any]
+    #[arg(long)]
+    trend: Option<Trend>,
+    /// How many levels the Problem Dampener may drop [default: 1]
+    #[arg(long)]
+    max_removals: Option<usize>,
+}
+
+impl PolicyArgs {
+    fn resolve(&self) -> Result<SafetyPolicy, String> {
+        let mut policy = match &self.policy {
+            Some(path) => {
+                let contents = fs::read_to_string(path)
+                    .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+                toml::from_str(&contents)
+                    .map_err(|err| format!("invalid {}: {}", path.display(), err))?
+            }
+            None => SafetyPolicy::default(),
+        };
+        policy.min_step = self.min_step.unwrap_or(policy.min_step);
+        policy.max_step = self.max_step.unwrap_or(policy.max_step);
+        policy.allow_plateaus = self.allow_plateaus.unwrap_or(policy.allow_plateaus);
+        policy.trend = self.trend.unwrap_or(policy.trend);
+        policy.max_removals = self.max_removals.unwrap_or(policy.max_removals);
+        policy.validate()?;
+        Ok(policy)
+    }
+}
+
+pub fn day2(command: Day2Command) -> ExitCode {
+    let policy = match &command {
+        Day2Command::Check { policy, .. }
+        | Day2Command::Removals { policy, .. }
+        | Day2Command::Monitor { policy, .. } => policy.resolve(),
+    };
+    let policy = match policy {
+        Ok(policy) => policy,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match command {
+        Day2Command::Check {
+            input,
+            explain: true,
+            json,
+            ..
+        } => explain_reports(input, &policy, json),
+        Day2Command::Check { input, .. } => {
+            let Some(reports) = load::<Day2>(2, input) else {
+                return ExitCode::FAILURE;
+            };
+            check(&reports, &policy);
+            ExitCode::SUCCESS
+        }
+        Day2Command::Removals { input, .. } => {
+            let Some(reports) = load_with(2, input, numbered_reports) else {
+                return ExitCode::FAILURE;
+            };
+            removals(&reports, &policy);
+            ExitCode::SUCCESS
+        }
+        Day2Command::Monitor { input, .. } => monitor(&input, policy),
+    }
+}
+
+fn explain_reports(input: Option<String>, policy: &SafetyPolicy, json: bool) -> ExitCode {
+    let Some(explanations) = load_with(2, input, |contents| explain(contents, policy)) else {
+        return ExitCode::FAILURE;
+    };
+    if json {
+        match serde_json::to_string_pretty(&explanations) {
+            Ok(json) => println!("{}", json),
+            Err(err) => {
+                eprintln!("failed to encode the explanations: {}", err);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        for explanation in &explanations {
+            println!("{}", explanation);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn check(reports: &[Vec<i32>], policy: &SafetyPolicy) {
+    let safe = reports.iter().filter(|numbers| policy.is_safe(numbers));
+    let dampened = reports
+        .iter()
+        .filter(|numbers| policy.is_safe_with_dampener(numbers));
+    println!("safe: {} of {}", safe.count(), reports.len());
+    println!(
+        "safe with up to {} removed: {} of {}",
+        policy.max_removals,
+        dampened.count(),
+        reports.len()
+    );
+}
+
+/// Each report with the input line it came from, skipping blank lines as the puzzle parser does.
+fn numbered_reports(contents: &str) -> ParseResult<Vec<(usize, Vec<i32>)>> {
+    numbered_lines(contents)
+        .filter(|line| !line.is_blank())
+        .map(|line| Ok((line.line, parse_report(line)?)))
+        .collect()
+}
+
+fn removals(reports: &[(usize, Vec<i32>)], policy: &SafetyPolicy) {
+    for (line, numbers) in reports {
+        let dropped = minimum_removals(numbers, policy);
+        if !dropped.is_empty() {
+            println!(
+                "line {}: drop indices {:?} to get {:?}",
+                line,
+                dropped,
+                without(numbers, &dropped)
+            );
+        }
+    }
+    let reports: Vec<Vec<i32>> = reports.iter().map(|(_, numbers)| numbers.clone()).collect();
+    print!("{}", RemovalSummary::new(&reports, policy));
+}
+
+fn monitor(path: &str, policy: SafetyPolicy) -> ExitCode {
+    let reader = match open_input(path) {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("failed to read {}: {}", path, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let file = input_label(path);
+
+    let mut monitor = Monitor::new(policy);
+    for (index, text) in reader.lines().enumerate() {
+        let text = match text {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("failed to read {}: {}", path, err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let line = Span {
+            line: index + 1,
+            column: 1,
+            text: &text,
+        };
+        // a bad line shouldn't stop a live tail, so report it and carry on
+        match monitor.feed(line) {
+            Ok(Some(alert)) => println!("ALERT {}", alert),
+            Ok(None) => {}
+            Err(err) => eprintln!("{}", err.with_file(file)),
+        }
+    }
+    ExitCode::SUCCESS
+}
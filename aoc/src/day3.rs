@@ -0,0 +1,176 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::process::ExitCode;
+
+use clap::{Subcommand, ValueEnum};
+use day_3::nested::{evaluate, TopLevel};
+use day_3::stream::stream_totals;
+use day_3::trace::{highlight, trace};
+use day_3::vm::{InstructionSet, Machine};
+use day_3::Day3;
+
+use crate::{default_input_path, load};
+
+#[derive(Subcommand)]
+pub enum Day3Command {
+    /// Replay the memory on the instruction interpreter and print the accumulator
+    Run {
+        /// Input file, or `-` for stdin [default: day-3/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        #[arg(long, value_enum, default_value_t = Instructions::Puzzle)]
+        instructions: Instructions,
+    },
+    /// List every instruction with where it is, whether it was enabled and what it added
+    Trace {
+        /// Input file, or `-` for stdin [default: day-3/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        #[arg(long, value_enum, default_value_t = Instructions::Puzzle)]
+        instructions: Instructions,
+        /// Re-print the memory instead, with counted instructions highlighted, toggles in
+        /// another colour and disabled instructions dimmed
+        #[arg(long)]
+        highlight: bool,
+    },
+    /// Evaluate nested calls like mul(mul(2,3),4) and compare with the strict grammar
+    Nested {
+        /// Input file, or `-` for stdin [default: day-3/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        #[arg(long, value_enum, default_value_t = Instructions::Puzzle)]
+        instructions: Instructions,
+        /// Print the evaluation tree of every top-level expression
+        #[arg(long)]
+        trees: bool,
+    },
+    /// Solve both parts reading the memory a chunk at a time, for dumps too big to load
+    Stream {
+        /// Input file, or `-` for stdin [default: day-3/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        /// Bytes to read and scan at a time
+        #[arg(long, default_value_t = 64 * 1024, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        chunk_size: usize,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Instructions {
+    /// mul, do and don't
+    Puzzle,
+    /// The puzzle's instructions plus add and sub
+    Extended,
+}
+
+impl Instructions {
+    fn set(self) -> InstructionSet {
+        match self {
+            Instructions::Puzzle => InstructionSet::puzzle(),
+            Instructions::Extended => InstructionSet::extended(),
+        }
+    }
+}
+
+pub fn day3(command: Day3Command) -> ExitCode {
+    match command {
+        Day3Command::Run {
+            input,
+            instructions,
+        } => {
+            let Some(memory) = load::<Day3>(3, input) else {
+                return ExitCode::FAILURE;
+            };
+            match Machine::new(&instructions.set()).run(&memory) {
+                Ok(accumulator) => {
+                    println!("accumulator: {}", accumulator);
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("{}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Day3Command::Trace {
+            input,
+            instructions,
+            highlight: colour,
+        } => {
+            let Some(memory) = load::<Day3>(3, input) else {
+                return ExitCode::FAILURE;
+            };
+            let entries = match trace(&memory, &instructions.set()) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            if colour {
+                print!("{}", highlight(&memory, &entries));
+            } else {
+                for entry in &entries {
+                    println!("{}", entry);
+                }
+                let total: i64 = entries.iter().map(|entry| entry.contribution()).sum();
+                println!("accumulator: {}", total);
+            }
+            ExitCode::SUCCESS
+        }
+        Day3Command::Nested {
+            input,
+            instructions,
+            trees,
+        } => nested(input, instructions.set(), trees),
+        Day3Command::Stream { input, chunk_size } => stream(input, chunk_size),
+    }
+}
+
+fn nested(input: Option<String>, set: InstructionSet, trees: bool) -> ExitCode {
+    let Some(memory) = load::<Day3>(3, input) else {
+        return ExitCode::FAILURE;
+    };
+    let (strict, found) = match Machine::new(&set)
+        .run(&memory)
+        .and_then(|strict| Ok((strict, evaluate(&memory, &set)?)))
+    {
+        Ok(results) => results,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if trees {
+        for top in &found {
+            print!("{}", top);
+        }
+    }
+    let nested: i64 = found.iter().map(TopLevel::contribution).sum();
+    println!("strict accumulator: {}", strict);
+    println!("nested accumulator: {}", nested);
+    ExitCode::SUCCESS
+}
+
+fn stream(input: Option<String>, chunk_size: usize) -> ExitCode {
+    let path = input.unwrap_or_else(|| default_input_path(3).display().to_string());
+    let reader: io::Result<Box<dyn BufRead>> = if path == "-" {
+        Ok(Box::new(BufReader::with_capacity(chunk_size, io::stdin())))
+    } else {
+        File::open(&path)
+            .map(|file| Box::new(BufReader::with_capacity(chunk_size, file)) as Box<dyn BufRead>)
+    };
+
+    match reader.and_then(|reader| stream_totals(reader, chunk_size)) {
+        Ok(totals) => {
+            println!("day 3 part 1: {}", totals.all);
+            println!("day 3 part 2: {}", totals.enabled);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("failed to read {}: {}", path, err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Subcommand;
+use day_4::dictionary::{parse_words, WordCounts};
+use day_4::Day4;
+
+use crate::{load, load_with};
+
+#[derive(Subcommand)]
+pub enum Day4Command {
+    /// Search for every word in a word list at once, in all eight directions
+    Words {
+        /// Input file, or `-` for stdin [default: day-4/src/data.txt]
+        #[arg(long)]
+        input: Option<String>,
+        /// Word list, one word per line
+        #[arg(long)]
+        words: PathBuf,
+        /// Also list where each word starts and which way it reads
+        #[arg(long)]
+        positions: bool,
+    },
+}
+
+pub fn day4(command: Day4Command) -> ExitCode {
+    match command {
+        Day4Command::Words {
+            input,
+            words,
+            positions,
+        } => {
+            let Some(words) = load_with(4, Some(words.display().to_string()), parse_words) else {
+                return ExitCode::FAILURE;
+            };
+            let Some(word_search) = load::<Day4>(4, input) else {
+                return ExitCode::FAILURE;
+            };
+            let counts = WordCounts::new(&word_search, words);
+
+            for (word, placements) in counts.words.iter().zip(&counts.placements) {
+                println!("{}: {}", word, placements.len());
+                if positions {
+                    for placement in placements {
+                        println!(
+                            "  row {}, column {}, {:?}",
+                            placement.start.row + 1,
+                            placement.start.col + 1,
+                            placement.direction
+                        );
+                    }
+                }
+            }
+            println!("total: {}", counts.total());
+            ExitCode::SUCCESS
+        }
+    }
+}
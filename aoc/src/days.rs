@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use aoc_core::generate::StdRng;
+use aoc_core::{bench_parts, run_parts, Answer, Generate, ParseResult, Solution, Stats};
+
+/// Parses a day's input and runs the requested parts, timing each one.
+pub type Runner = fn(&str, &[u8]) -> ParseResult<Vec<(Answer, Duration)>>;
+
+/// Benchmarks a day's parse, part 1 and part 2 given warmup and iteration counts.
+pub type Bencher = fn(&str, usize, usize) -> ParseResult<[Stats; 3]>;
+
+/// Makes up a random valid input for a day.
+pub type Generator = fn(&mut StdRng) -> String;
+
+/// The entry points the runner needs for one solved day.
+pub struct Day {
+    pub run: Runner,
+    pub bench: Bencher,
+    pub generate: Generator,
+}
+
+impl Day {
+    fn of<S: Solution + Generate>() -> Self {
+        Self {
+            run: run_parts::<S>,
+            bench: bench_parts::<S>,
+            generate: S::generate,
+        }
+    }
+}
+
+/// Returns the entry points for a day, if it has been solved.
+pub fn day(day: u8) -> Option<Day> {
+    let day = match day {
+        1 => Day::of::<day_1::Day1>(),
+        2 => Day::of::<day_2::Day2>(),
+        3 => Day::of::<day_3::Day3>(),
+        4 => Day::of::<day_4::Day4>(),
+        5 => Day::of::<day_5::Day5>(),
+        6 => Day::of::<day_6::Day6>(),
+        7 => Day::of::<day_7::Day7>(),
+        8 => Day::of::<day_8::Day8>(),
+        9 => Day::of::<day_9::Day9>(),
+        10 => Day::of::<day_10::Day10>(),
+        11 => Day::of::<day_11::Day11>(),
+        12 => Day::of::<day_12::Day12>(),
+        _ => return None,
+    };
+    Some(day)
+}
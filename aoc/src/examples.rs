@@ -0,0 +1,72 @@
+//! Runs every bundled puzzle example against its recorded answers.
+//!
+//! Examples sit next to each day's input as `day-N/src/example*.txt`, and their expected
+//! answers live in `answers.toml` alongside the real inputs. Dropping in a new example file
+//! without recording both of its answers fails the suite.
+
+use std::fs;
+use std::path::Path;
+
+use crate::answers::AnswerStore;
+use crate::{days, workspace_root};
+
+/// Every `day-N/src/example*.txt` under `root`, as its day and its path relative to `root`.
+fn discover(root: &Path) -> Vec<(u8, String)> {
+    let mut examples = Vec::new();
+    for day in 1..=25 {
+        let Ok(entries) = fs::read_dir(root.join(format!("day-{}", day)).join("src")) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.starts_with("example") && name.ends_with(".txt") {
+                examples.push((day, format!("day-{}/src/{}", day, name)));
+            }
+        }
+    }
+    examples.sort();
+    examples
+}
+
+#[test]
+fn test_examples_match_recorded_answers() {
+    let root = workspace_root();
+    let store = AnswerStore::load(&root.join("answers.toml")).unwrap();
+    let examples = discover(&root);
+    assert!(!examples.is_empty(), "no example files found");
+
+    let mut failures = Vec::new();
+    for (day, input) in &examples {
+        let Some(solved) = days::day(*day) else {
+            failures.push(format!("{}: no solver registered for day {}", input, day));
+            continue;
+        };
+        let contents = fs::read_to_string(root.join(input)).unwrap();
+
+        for part in [1, 2] {
+            let Some(recorded) = store.answers.iter().find(|recorded| {
+                recorded.day == *day && recorded.part == part && recorded.input == *input
+            }) else {
+                failures.push(format!(
+                    "{} part {}: no answer in answers.toml",
+                    input, part
+                ));
+                continue;
+            };
+            match (solved.run)(&contents, &[part]) {
+                Ok(answers) => {
+                    let actual = answers[0].0.to_string();
+                    if actual != recorded.answer {
+                        failures.push(format!(
+                            "{} part {}: expected {}, got {}",
+                            input, part, recorded.answer, actual
+                        ));
+                    }
+                }
+                Err(err) => failures.push(format!("{}: {}", input, err)),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,368 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+const BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2024;
+const USER_AGENT: &str = "aoc-runner (puzzle input fetcher)";
+/// The longest `Retry-After` worth waiting out; anything longer fails straight away.
+const MAX_RETRY_AFTER: u64 = 60;
+
+/// The parts of an HTTP response the fetcher looks at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Response {
+    /// The first header called `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Sends GET requests. Any status is a response; only transport failures are errors.
+pub trait HttpClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, String>;
+}
+
+/// The real client, backed by `ureq`.
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl UreqClient {
+    pub fn new() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, String> {
+        let mut request = self.agent.get(url);
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+        let response = match request.call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(err.to_string()),
+        };
+
+        let status = response.status();
+        let headers = response
+            .headers_names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = response.header(&name)?.to_string();
+                Some((name, value))
+            })
+            .collect();
+        let body = response
+            .into_string()
+            .map_err(|err| format!("failed to read response body: {}", err))?;
+        Ok(Response {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// `~/.config/aoc/config.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    session: Option<String>,
+}
+
+fn config_path() -> Option<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_home.join("aoc").join("config.toml"))
+}
+
+/// The session cookie from `AOC_SESSION`, falling back to `session` in the config file.
+fn session_token() -> Result<Option<String>, String> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(Some(session.trim().to_string()));
+        }
+    }
+    let Some(path) = config_path().filter(|path| path.exists()) else {
+        return Ok(None);
+    };
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+    let config: Config =
+        toml::from_str(&contents).map_err(|err| format!("invalid {}: {}", path.display(), err))?;
+    Ok(config
+        .session
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty()))
+}
+
+/// Where `aoc fetch` keeps downloaded inputs under the workspace root.
+pub fn cache_dir(root: &Path) -> PathBuf {
+    root.join("inputs")
+}
+
+/// The cached input for `day` in `cache_dir`.
+pub fn cached_input(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir.join(format!("day-{}.txt", day))
+}
+
+/// Downloads puzzle inputs once and serves them from the cache afterwards.
+pub struct Fetcher<'a> {
+    pub client: &'a dyn HttpClient,
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+    /// How many times to wait out a `Retry-After` of up to a minute before giving up.
+    pub max_retries: u32,
+}
+
+impl Fetcher<'_> {
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        cached_input(&self.cache_dir, day)
+    }
+
+    /// Returns the cached input path, downloading it first if it is not cached yet.
+    /// The flag says whether a download happened.
+    pub fn fetch(&self, day: u8) -> Result<(PathBuf, bool), String> {
+        let path = self.cached_path(day);
+        if path.exists() {
+            return Ok((path, false));
+        }
+
+        let session = self.session.as_deref().ok_or_else(|| {
+            "no session token: set AOC_SESSION or `session` in ~/.config/aoc/config.toml"
+                .to_string()
+        })?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let cookie = format!("session={}", session);
+        let headers = [("Cookie", cookie.as_str()), ("User-Agent", USER_AGENT)];
+
+        let mut attempt = 0;
+        let response = loop {
+            let response = self.client.get(&url, &headers)?;
+            if !matches!(response.status, 429 | 503) {
+                break response;
+            }
+            let retry_after = response.header("Retry-After");
+            let wait = retry_after.and_then(|value| value.trim().parse::<u64>().ok());
+            match wait {
+                Some(seconds) if attempt < self.max_retries && seconds <= MAX_RETRY_AFTER => {
+                    attempt += 1;
+                    thread::sleep(Duration::from_secs(seconds));
+                }
+                _ => {
+                    return Err(format!(
+                        "rate limited by {} (Retry-After: {})",
+                        self.base_url,
+                        retry_after.unwrap_or("not given")
+                    ))
+                }
+            }
+        };
+
+        match response.status {
+            200 if !response.body.is_empty() => {}
+            200 => return Err(format!("day {} input was empty", day)),
+            400 | 401 => {
+                return Err(format!(
+                    "the server rejected the session token (status {}); it may have expired",
+                    response.status
+                ))
+            }
+            404 => return Err(format!("day {} input is not available yet", day)),
+            500..=599 => {
+                return Err(format!(
+                    "server error (status {}) from {}; try again later",
+                    response.status, self.base_url
+                ))
+            }
+            status => return Err(format!("unexpected status {} from {}", status, url)),
+        }
+
+        fs::create_dir_all(&self.cache_dir)
+            .map_err(|err| format!("failed to create {}: {}", self.cache_dir.display(), err))?;
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, &response.body)
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|err| format!("failed to write {}: {}", path.display(), err))?;
+        Ok((path, true))
+    }
+}
+
+/// Fetches one day's input into `inputs/` under the workspace root.
+pub fn fetch(root: &Path, day: u8) -> ExitCode {
+    let session = match session_token() {
+        Ok(session) => session,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = UreqClient::new();
+    let fetcher = Fetcher {
+        client: &client,
+        base_url: BASE_URL.to_string(),
+        session,
+        cache_dir: cache_dir(root),
+        max_retries: 3,
+    };
+
+    match fetcher.fetch(day) {
+        Ok((path, true)) => println!("downloaded day {} to {}", day, path.display()),
+        Ok((path, false)) => println!("day {} is already cached at {}", day, path.display()),
+        Err(err) => {
+            eprintln!("failed to fetch day {}: {}", day, err);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Serves each canned `(status, headers, body)` to one connection in turn, then stops.
+    /// Joining the handle gives back the request heads it received.
+    fn serve(
+        responses: Vec<(u16, &'static str, &'static str)>,
+    ) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, headers, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    head += &line;
+                }
+                requests.push(head);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Canned\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    headers,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher<'a>(client: &'a UreqClient, base_url: String, cache_dir: PathBuf) -> Fetcher<'a> {
+        Fetcher {
+            client,
+            base_url,
+            session: Some("abc123".to_string()),
+            cache_dir,
+            max_retries: 1,
+        }
+    }
+
+    #[test]
+    fn test_downloads_once_then_uses_cache() {
+        let (base_url, server) = serve(vec![(200, "", "3   4\n4   3\n")]);
+        let client = UreqClient::new();
+        let fetcher = fetcher(&client, base_url, cache_dir("cache"));
+
+        let (path, downloaded) = fetcher.fetch(1).unwrap();
+        assert!(downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+        assert_eq!(fetcher.fetch(1).unwrap(), (path, false));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+        assert!(requests[0]
+            .to_ascii_lowercase()
+            .contains("cookie: session=abc123"));
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_waits_out_retry_after() {
+        let (base_url, server) =
+            serve(vec![(429, "Retry-After: 0\r\n", ""), (200, "", "125 17\n")]);
+        let client = UreqClient::new();
+        let fetcher = fetcher(&client, base_url, cache_dir("retry"));
+
+        let (path, downloaded) = fetcher.fetch(11).unwrap();
+        assert!(downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "125 17\n");
+        assert_eq!(server.join().unwrap().len(), 2);
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_long_retry_after_fails_without_waiting() {
+        let (base_url, server) = serve(vec![(429, "Retry-After: 36000\r\n", "")]);
+        let client = UreqClient::new();
+        let fetcher = fetcher(&client, base_url, cache_dir("long-retry"));
+
+        let err = fetcher.fetch(3).unwrap_err();
+        assert!(err.contains("Retry-After: 36000"), "{}", err);
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_server_error_is_not_blamed_on_the_session() {
+        let (base_url, server) = serve(vec![(500, "", "Oops")]);
+        let client = UreqClient::new();
+        let fetcher = fetcher(&client, base_url, cache_dir("server-error"));
+
+        let err = fetcher.fetch(4).unwrap_err();
+        assert!(err.starts_with("server error (status 500)"), "{}", err);
+        assert!(!fetcher.cached_path(4).exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_rejected_session_is_not_cached() {
+        let (base_url, server) = serve(vec![(400, "", "Please log in.")]);
+        let client = UreqClient::new();
+        let fetcher = fetcher(&client, base_url, cache_dir("rejected"));
+
+        let err = fetcher.fetch(2).unwrap_err();
+        assert!(err.contains("session token"), "{}", err);
+        assert!(!fetcher.cached_path(2).exists());
+        server.join().unwrap();
+    }
+}
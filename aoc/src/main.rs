@@ -0,0 +1,244 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_core::Solution;
+use clap::builder::RangedU64ValueParser;
+use clap::{Parser, Subcommand};
+
+mod answers;
+mod bench;
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod days;
+#[cfg(test)]
+mod examples;
+mod fetch;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day against an input file
+    Run {
+        /// Puzzle day, 1-25
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Only run this part; both parts run when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or `-` for stdin [default: day-N/src/data.txt, else inputs/day-N.txt]
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Re-run every recorded answer and fail if any have changed
+    Verify {
+        /// Only check this day
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Answers file [default: answers.toml in the workspace root]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Print a random valid input for a day
+    Generate {
+        /// Puzzle day, 1-25
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Seed for the random generator; the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Download a day's puzzle input into the inputs/ cache
+    Fetch {
+        /// Puzzle day, 1-25
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Time each day's parse, part 1 and part 2 over many runs
+    Bench {
+        /// Only benchmark this day
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Untimed runs before sampling starts
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed runs per step
+        #[arg(long, default_value_t = 20, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        iterations: usize,
+        /// Where to write the JSON results [default: target/aoc-bench.json]
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Earlier JSON results to compare medians against
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Fractional slowdown in the median that counts as a regression
+        #[arg(long, default_value_t = 0.1)]
+        threshold: f64,
+    },
+    /// Day 1 extras beyond the plain solution
+    Day1 {
+        #[command(subcommand)]
+        command: day1::Day1Command,
+    },
+    /// Day 2 extras beyond the plain solution
+    Day2 {
+        #[command(subcommand)]
+        command: day2::Day2Command,
+    },
+    /// Day 3 extras beyond the plain solution
+    Day3 {
+        #[command(subcommand)]
+        command: day3::Day3Command,
+    },
+    /// Day 4 extras beyond the plain solution
+    Day4 {
+        #[command(subcommand)]
+        command: day4::Day4Command,
+    },
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc lives inside the workspace")
+        .to_path_buf()
+}
+
+/// The checked-in puzzle input for a day, or the one `aoc fetch` cached if none is checked in.
+fn default_input_path(day: u8) -> PathBuf {
+    let root = workspace_root();
+    let checked_in = root
+        .join(format!("day-{}", day))
+        .join("src")
+        .join("data.txt");
+    if checked_in.exists() {
+        return checked_in;
+    }
+    fetch::cached_input(&fetch::cache_dir(&root), day)
+}
+
+/// Reads and parses a day's input, `-` meaning stdin, printing a diagnostic on failure.
+fn load<S: Solution>(day: u8, input: Option<String>) -> Option<S::Input> {
+    load_with(day, input, S::parse)
+}
+
+/// Like [`load`], with a parser other than the day's own.
+fn load_with<T>(
+    day: u8,
+    input: Option<String>,
+    parse: impl FnOnce(&str) -> aoc_core::ParseResult<T>,
+) -> Option<T> {
+    let path = input.unwrap_or_else(|| default_input_path(day).display().to_string());
+    let contents = match aoc_core::read_input(&path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("failed to read {}: {}", path, err);
+            return None;
+        }
+    };
+    match parse(&contents) {
+        Ok(input) => Some(input),
+        Err(err) => {
+            eprint!(
+                "{}",
+                err.with_file(input_label(&path)).diagnostic(&contents)
+            );
+            None
+        }
+    }
+}
+
+/// How diagnostics name an input path, `-` being stdin.
+fn input_label(path: &str) -> &str {
+    if path == "-" {
+        "<stdin>"
+    } else {
+        path
+    }
+}
+
+/// Opens `path` for buffered reading, with `-` meaning stdin.
+fn open_input(path: &str) -> io::Result<Box<dyn BufRead>> {
+    if path == "-" {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: Option<String>) -> ExitCode {
+    let Some(solved) = days::day(day) else {
+        eprintln!("day {} has no solution yet", day);
+        return ExitCode::FAILURE;
+    };
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let Some(answers) = load_with(day, input, |contents| (solved.run)(contents, &parts)) else {
+        return ExitCode::FAILURE;
+    };
+    for (part, (answer, duration)) in parts.iter().zip(answers) {
+        println!("day {} part {}: {} ({:?})", day, part, answer, duration);
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn generate(day: u8, seed: u64) -> ExitCode {
+    let Some(solved) = days::day(day) else {
+        eprintln!("day {} has no solution yet", day);
+        return ExitCode::FAILURE;
+    };
+    print!(
+        "{}",
+        (solved.generate)(&mut aoc_core::generate::seeded(seed))
+    );
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run { day, part, input } => run(day, part, input),
+        Command::Verify { day, answers } => {
+            let answers = answers.unwrap_or_else(|| workspace_root().join("answers.toml"));
+            verify::verify(&workspace_root(), &answers, day)
+        }
+        Command::Generate { day, seed } => generate(day, seed),
+        Command::Fetch { day } => fetch::fetch(&workspace_root(), day),
+        Command::Bench {
+            day,
+            warmup,
+            iterations,
+            output,
+            baseline,
+            threshold,
+        } => {
+            let output =
+                output.unwrap_or_else(|| workspace_root().join("target").join("aoc-bench.json"));
+            bench::bench(&bench::BenchOptions {
+                day,
+                warmup,
+                iterations,
+                output: &output,
+                baseline: baseline.as_deref(),
+                threshold,
+            })
+        }
+        Command::Day1 { command } => day1::day1(command),
+        Command::Day2 { command } => day2::day2(command),
+        Command::Day3 { command } => day3::day3(command),
+        Command::Day4 { command } => day4::day4(command),
+    }
+}
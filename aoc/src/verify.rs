@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::ExitCode;
+
+use crate::answers::{AnswerStore, RecordedAnswer};
+use crate::days;
+
+/// Re-runs every recorded answer and reports any that changed.
+pub fn verify(root: &Path, answers_path: &Path, day: Option<u8>) -> ExitCode {
+    let store = match AnswerStore::load(answers_path) {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // group by day and input so each input is read and parsed once
+    let mut grouped: BTreeMap<(u8, &str), Vec<&RecordedAnswer>> = BTreeMap::new();
+    for recorded in &store.answers {
+        if day.is_none_or(|day| day == recorded.day) {
+            grouped
+                .entry((recorded.day, recorded.input.as_str()))
+                .or_default()
+                .push(recorded);
+        }
+    }
+
+    let total: usize = grouped.values().map(Vec::len).sum();
+    let mut failures = 0;
+    for ((day, input), recorded) in grouped {
+        let label = format!("day {} ({})", day, input);
+        let Some(solved) = days::day(day) else {
+            println!("{}: FAILED\n  no solver registered", label);
+            failures += recorded.len();
+            continue;
+        };
+        let contents = match aoc_core::read_input(&root.join(input).display().to_string()) {
+            Ok(contents) => contents,
+            Err(err) => {
+                println!("{}: FAILED\n  could not read input: {}", label, err);
+                failures += recorded.len();
+                continue;
+            }
+        };
+
+        let parts: Vec<u8> = recorded.iter().map(|recorded| recorded.part).collect();
+        let answers = match (solved.run)(&contents, &parts) {
+            Ok(answers) => answers,
+            Err(err) => {
+                println!("{}: FAILED\n  could not parse input: {}", label, err);
+                failures += recorded.len();
+                continue;
+            }
+        };
+
+        for (recorded, (answer, _)) in recorded.iter().zip(answers) {
+            let actual = answer.to_string();
+            if actual == recorded.answer {
+                println!("day {} part {} ({}): ok", day, recorded.part, input);
+            } else {
+                failures += 1;
+                println!(
+                    "day {} part {} ({}): FAILED\n  - {}\n  + {}",
+                    day, recorded.part, input, recorded.answer, actual
+                );
+            }
+        }
+    }
+
+    if failures > 0 {
+        println!("{} of {} answers failed", failures, total);
+        return ExitCode::FAILURE;
+    }
+    println!("{} answers verified", total);
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,152 @@
+//! A faster path for lists whose IDs fall in a narrow range, like the puzzle's five-digit IDs.
+//! A flat array of occurrence counts stands in for both the comparison sort and the `HashMap`
+//! lookup. Lists spread over wider ranges fall back to the general functions.
+
+use crate::{calculate_sum_of_differences, calculate_weighted_sum};
+
+/// Ranges up to this many IDs always get a count array: 4 MiB of counters.
+const SMALL_SPAN: usize = 1 << 20;
+/// Ranges wider than this never do, however long the lists are.
+const MAX_SPAN: usize = 1 << 26;
+
+/// How many times each ID in `min..min + counts.len()` occurs.
+pub struct DenseCounts {
+    min: i64,
+    counts: Vec<u32>,
+}
+
+impl DenseCounts {
+    /// The lowest ID in `arrays` and how many slots reach the highest, if that range is
+    /// narrow enough for a flat array: up to [`SMALL_SPAN`], or four slots per ID for longer
+    /// lists.
+    pub fn range_of(arrays: &[&[i64]]) -> Option<(i64, usize)> {
+        let len: usize = arrays.iter().map(|array| array.len()).sum();
+        let ids = || arrays.iter().flat_map(|array| array.iter().copied());
+        let (min, max) = (ids().min()?, ids().max()?);
+
+        let span = max as i128 - min as i128 + 1;
+        let limit = SMALL_SPAN.max(4 * len).min(MAX_SPAN);
+        (span <= limit as i128 && len <= u32::MAX as usize).then_some((min, span as usize))
+    }
+
+    /// Counts `array`, every ID of which must lie in `min..min + span`.
+    pub fn new(array: &[i64], (min, span): (i64, usize)) -> Self {
+        let mut counts = vec![0; span];
+        for &id in array {
+            counts[(id - min) as usize] += 1;
+        }
+        Self { min, counts }
+    }
+
+    pub fn get(&self, id: i64) -> u32 {
+        id.checked_sub(self.min)
+            .and_then(|offset| usize::try_from(offset).ok())
+            .and_then(|index| self.counts.get(index))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Each ID that occurs, smallest first, with its count.
+    pub fn ids(&self) -> impl Iterator<Item = (i64, u32)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(offset, &count)| (self.min + offset as i64, count))
+    }
+}
+
+/// Sorts `array`, with a counting sort when its IDs span a narrow range.
+pub fn sort_ids(array: &mut [i64]) {
+    let Some(range) = DenseCounts::range_of(&[array]) else {
+        array.sort_unstable();
+        return;
+    };
+    let counts = DenseCounts::new(array, range);
+    let mut slots = array.iter_mut();
+    for (id, count) in counts.ids() {
+        for slot in slots.by_ref().take(count as usize) {
+            *slot = id;
+        }
+    }
+}
+
+/// Same as sorting both lists and calling [`calculate_sum_of_differences`].
+pub fn sum_of_differences(array1: &[i64], array2: &[i64]) -> i128 {
+    let Some(range) = DenseCounts::range_of(&[array1, array2]) else {
+        let mut array1 = array1.to_vec();
+        let mut array2 = array2.to_vec();
+        array1.sort_unstable();
+        array2.sort_unstable();
+        return calculate_sum_of_differences(&array1, &array2);
+    };
+
+    // walk both sets of counts in order, pairing off as many copies of each ID as the other
+    // side has at its current ID, instead of pairing one element at a time
+    let left = DenseCounts::new(array1, range);
+    let right = DenseCounts::new(array2, range);
+    let mut rights = right.ids();
+    let mut current = rights.next();
+    let mut total = 0;
+    for (id, mut count) in left.ids() {
+        while count > 0 {
+            let Some((right_id, right_count)) = current.as_mut() else {
+                return total;
+            };
+            let paired = count.min(*right_count);
+            total += id.abs_diff(*right_id) as i128 * paired as i128;
+            count -= paired;
+            *right_count -= paired;
+            if *right_count == 0 {
+                current = rights.next();
+            }
+        }
+    }
+    total
+}
+
+/// Same as [`calculate_weighted_sum`].
+pub fn weighted_sum(array1: &[i64], array2: &[i64]) -> i128 {
+    let Some(range) = DenseCounts::range_of(&[array1, array2]) else {
+        return calculate_weighted_sum(array1, array2);
+    };
+
+    let lookup = DenseCounts::new(array2, range);
+    array1
+        .iter()
+        .map(|&id| id as i128 * lookup.get(id) as i128)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_ranges_fall_back() {
+        let array1 = [3, 1_000_000_000_000, -7, 3];
+        let array2 = [3, 3, 1_000_000_000_000, 12];
+        assert!(DenseCounts::range_of(&[&array1, &array2]).is_none());
+
+        let mut sorted1 = array1.to_vec();
+        let mut sorted2 = array2.to_vec();
+        sort_ids(&mut sorted1);
+        sort_ids(&mut sorted2);
+        assert_eq!(sorted1, vec![-7, 3, 3, 1_000_000_000_000]);
+        assert_eq!(
+            sum_of_differences(&array1, &array2),
+            calculate_sum_of_differences(&sorted1, &sorted2)
+        );
+        assert_eq!(
+            weighted_sum(&array1, &array2),
+            3 * 2 * 2 + 1_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_counting_sort_handles_negative_ids() {
+        let mut array = vec![5, -2, 0, 5, -9, 5, 1];
+        sort_ids(&mut array);
+        assert_eq!(array, vec![-9, -2, 0, 1, 5, 5, 5]);
+    }
+}
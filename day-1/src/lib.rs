@@ -0,0 +1,155 @@
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{numbered_lines, Answer, Generate, ParseResult, Solution, Span};
+use std::collections::HashMap;
+
+pub mod dense;
+pub mod report;
+pub mod similarity;
+pub mod stream;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<i64>, Vec<i64>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        split_arrays(input)
+    }
+
+    /// Sorts both lists and sums the distance between each pair.
+    fn part1((array1, array2): &Self::Input) -> Answer {
+        dense::sum_of_differences(array1, array2).into()
+    }
+
+    /// Scores each left-list value by how often it appears in the right list.
+    fn part2((array1, array2): &Self::Input) -> Answer {
+        dense::weighted_sum(array1, array2).into()
+    }
+}
+
+impl Generate for Day1 {
+    /// Two columns of five-digit location IDs drawn from a small pool, so the right list
+    /// repeats some of the left list's IDs.
+    fn generate(rng: &mut StdRng) -> String {
+        let pool: Vec<i64> = (0..rng.random_range(1..20))
+            .map(|_| rng.random_range(10000..100000))
+            .collect();
+        (0..rng.random_range(1..50))
+            .map(|_| {
+                let left = pool[rng.random_range(0..pool.len())];
+                let right = pool[rng.random_range(0..pool.len())];
+                format!("{}   {}\n", left, right)
+            })
+            .collect()
+    }
+}
+
+/// Splits the input string into two arrays of integers.
+pub fn split_arrays(contents: &str) -> ParseResult<(Vec<i64>, Vec<i64>)> {
+    let mut array1 = Vec::new();
+    let mut array2 = Vec::new();
+
+    for line in numbered_lines(contents).filter(|line| !line.is_blank()) {
+        let (number1, number2) = parse_pair(line)?;
+        array1.push(number1);
+        array2.push(number2);
+    }
+
+    Ok((array1, array2))
+}
+
+/// Reads the left and right location IDs from one non-blank line.
+pub fn parse_pair(line: Span) -> ParseResult<(i64, i64)> {
+    let mut numbers = line.fields();
+    let number1: i64 = numbers
+        .next()
+        .unwrap_or(line.end())
+        .parse("the left location ID")?;
+    let number2: i64 = numbers
+        .next()
+        .unwrap_or(line.end())
+        .parse("the right location ID")?;
+    if let Some(extra) = numbers.next() {
+        return Err(extra.error("end of line"));
+    }
+    Ok((number1, number2))
+}
+
+/// Calculates the sum of absolute differences between two sorted arrays. IDs may use the
+/// whole `i64` range, so the sum is an `i128`.
+pub fn calculate_sum_of_differences(array1: &[i64], array2: &[i64]) -> i128 {
+    array1
+        .iter()
+        .zip(array2.iter())
+        .map(|(a, b)| a.abs_diff(*b) as i128)
+        .sum()
+}
+
+/// Counts how many times each value occurs in `array`.
+pub fn count_occurrences(array: &[i64]) -> HashMap<i64, i64> {
+    let mut lookup: HashMap<i64, i64> = HashMap::new();
+    for number in array {
+        let count = lookup.entry(*number).or_insert(0);
+        *count += 1;
+    }
+    lookup
+}
+
+/// Calculates a weighted sum of elements in array1 based on their occurrences in array2, as an
+/// `i128` like [`calculate_sum_of_differences`].
+pub fn calculate_weighted_sum(array1: &[i64], array2: &[i64]) -> i128 {
+    // Create a lookup for occurrences in array2
+    let lookup = count_occurrences(array2);
+
+    // Calculate the weighted sum
+    let mut sum = 0;
+    for number in array1 {
+        if let Some(&count) = lookup.get(number) {
+            sum += *number as i128 * count as i128;
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::check_against_reference;
+
+    /// Pairs the lists up by repeatedly taking the smallest left-over value from each.
+    fn reference(input: &str) -> (Answer, Answer) {
+        let (mut left, mut right) = split_arrays(input).unwrap();
+
+        let mut distance: i64 = 0;
+        while !left.is_empty() {
+            let smallest_left = (0..left.len()).min_by_key(|&i| left[i]).unwrap();
+            let smallest_right = (0..right.len()).min_by_key(|&i| right[i]).unwrap();
+            distance += (left.remove(smallest_left) - right.remove(smallest_right)).abs();
+        }
+
+        let (left, right) = split_arrays(input).unwrap();
+        let similarity: i64 = left
+            .iter()
+            .map(|a| a * right.iter().filter(|&b| b == a).count() as i64)
+            .sum();
+
+        (distance.into(), similarity.into())
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day1>(100, reference);
+    }
+
+    #[test]
+    fn test_extreme_ids_do_not_overflow() {
+        let input = format!("{} {}\n{} {}\n", i64::MIN, i64::MAX, i64::MAX, i64::MAX);
+        let lists = Day1::parse(&input).unwrap();
+
+        assert_eq!(Day1::part1(&lists), Answer::Text(u64::MAX.to_string()));
+        assert_eq!(
+            Day1::part2(&lists),
+            Answer::Text((i64::MAX as i128 * 2).to_string())
+        );
+    }
+}
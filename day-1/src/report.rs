@@ -0,0 +1,275 @@
+//! A breakdown of both day-1 totals, for explaining answers that look wrong.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{calculate_sum_of_differences, calculate_weighted_sum, count_occurrences};
+
+/// How wide the longest histogram bar is drawn in the text report.
+const BAR_WIDTH: usize = 40;
+
+/// One pair of the sorted lists. `rank` is its 1-based position after sorting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PairDistance {
+    pub rank: usize,
+    pub left: i64,
+    pub right: i64,
+    pub distance: u64,
+}
+
+/// How many pairs have a distance between `from` and `to`, inclusive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub pairs: usize,
+}
+
+/// A left-list ID's share of the similarity score: `id × left_count × right_count`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Contributor {
+    pub id: i64,
+    pub left_count: i64,
+    pub right_count: i64,
+    pub contribution: i128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub total_distance: i128,
+    pub similarity: i128,
+    pub pairs: Vec<PairDistance>,
+    pub histogram: Vec<Bucket>,
+    /// The pairs with the largest distances, largest first.
+    pub top_pairs: Vec<PairDistance>,
+    /// The left-list IDs adding the most to the similarity score, largest first.
+    pub top_contributors: Vec<Contributor>,
+}
+
+impl Report {
+    /// Builds the report for two unsorted lists, keeping `top` entries in each ranking and
+    /// splitting the distances into at most `buckets` equal-width histogram buckets.
+    pub fn new(array1: &[i64], array2: &[i64], top: usize, buckets: usize) -> Self {
+        let mut sorted1 = array1.to_vec();
+        let mut sorted2 = array2.to_vec();
+        sorted1.sort();
+        sorted2.sort();
+
+        let pairs: Vec<PairDistance> = sorted1
+            .iter()
+            .zip(&sorted2)
+            .enumerate()
+            .map(|(index, (&left, &right))| PairDistance {
+                rank: index + 1,
+                left,
+                right,
+                distance: left.abs_diff(right),
+            })
+            .collect();
+
+        let mut top_pairs = pairs.clone();
+        top_pairs.sort_by_key(|pair| std::cmp::Reverse(pair.distance));
+        top_pairs.truncate(top);
+
+        // the same lookup calculate_weighted_sum scores against
+        let lookup = count_occurrences(array2);
+        let mut top_contributors: Vec<Contributor> = count_occurrences(array1)
+            .into_iter()
+            .filter_map(|(id, left_count)| {
+                let right_count = *lookup.get(&id)?;
+                Some(Contributor {
+                    id,
+                    left_count,
+                    right_count,
+                    contribution: id as i128 * left_count as i128 * right_count as i128,
+                })
+            })
+            .collect();
+        top_contributors.sort_by_key(|c| (std::cmp::Reverse(c.contribution), c.id));
+        top_contributors.truncate(top);
+
+        Self {
+            total_distance: calculate_sum_of_differences(&sorted1, &sorted2),
+            similarity: calculate_weighted_sum(array1, array2),
+            histogram: histogram(&pairs, buckets),
+            pairs,
+            top_pairs,
+            top_contributors,
+        }
+    }
+
+    /// Every table in the report as CSV, each under a `# name` line and separated by a blank
+    /// line.
+    pub fn to_csv(&self) -> String {
+        let mut out = format!(
+            "# totals\ntotal_distance,similarity\n{},{}\n",
+            self.total_distance, self.similarity
+        );
+        for (name, pairs) in [("pairs", &self.pairs), ("top_pairs", &self.top_pairs)] {
+            out += &format!("\n# {}\nrank,left,right,distance\n", name);
+            for pair in pairs {
+                out += &format!(
+                    "{},{},{},{}\n",
+                    pair.rank, pair.left, pair.right, pair.distance
+                );
+            }
+        }
+        out += "\n# histogram\nfrom,to,pairs\n";
+        for bucket in &self.histogram {
+            out += &format!("{},{},{}\n", bucket.from, bucket.to, bucket.pairs);
+        }
+        out += "\n# top_contributors\nid,left_count,right_count,contribution\n";
+        for c in &self.top_contributors {
+            out += &format!(
+                "{},{},{},{}\n",
+                c.id, c.left_count, c.right_count, c.contribution
+            );
+        }
+        out
+    }
+}
+
+/// Splits `0..=max distance` into equal-width buckets and counts the pairs in each.
+fn histogram(pairs: &[PairDistance], buckets: usize) -> Vec<Bucket> {
+    let Some(max) = pairs.iter().map(|pair| pair.distance).max() else {
+        return Vec::new();
+    };
+    // saturating, since the widest distance between two i64s is u64::MAX
+    let width = (max / buckets.max(1) as u64).saturating_add(1);
+    let mut histogram: Vec<Bucket> = (0..=max / width)
+        .map(|i| Bucket {
+            from: i * width,
+            to: (i * width).saturating_add(width - 1),
+            pairs: 0,
+        })
+        .collect();
+    for pair in pairs {
+        histogram[(pair.distance / width) as usize].pairs += 1;
+    }
+    histogram
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "total distance: {}", self.total_distance)?;
+        writeln!(f, "similarity score: {}", self.similarity)?;
+
+        writeln!(f, "\nsorted pairs:")?;
+        writeln!(
+            f,
+            "{:>6} {:>10} {:>10} {:>10}",
+            "rank", "left", "right", "distance"
+        )?;
+        for pair in &self.pairs {
+            writeln!(
+                f,
+                "{:>6} {:>10} {:>10} {:>10}",
+                pair.rank, pair.left, pair.right, pair.distance
+            )?;
+        }
+
+        writeln!(f, "\ndistance histogram:")?;
+        let most = self.histogram.iter().map(|b| b.pairs).max().unwrap_or(0);
+        for bucket in &self.histogram {
+            let bar = (bucket.pairs * BAR_WIDTH).div_ceil(most.max(1));
+            writeln!(
+                f,
+                "{:>10}..={:<10} {:<width$} {}",
+                bucket.from,
+                bucket.to,
+                "#".repeat(bar),
+                bucket.pairs,
+                width = BAR_WIDTH
+            )?;
+        }
+
+        writeln!(f, "\ntop {} pairs by distance:", self.top_pairs.len())?;
+        writeln!(
+            f,
+            "{:>6} {:>10} {:>10} {:>10}",
+            "rank", "left", "right", "distance"
+        )?;
+        for pair in &self.top_pairs {
+            writeln!(
+                f,
+                "{:>6} {:>10} {:>10} {:>10}",
+                pair.rank, pair.left, pair.right, pair.distance
+            )?;
+        }
+
+        writeln!(
+            f,
+            "\ntop {} similarity contributors:",
+            self.top_contributors.len()
+        )?;
+        writeln!(
+            f,
+            "{:>10} {:>8} {:>8} {:>14}",
+            "id", "in left", "in right", "contribution"
+        )?;
+        for c in &self.top_contributors {
+            writeln!(
+                f,
+                "{:>10} {:>8} {:>8} {:>14}",
+                c.id, c.left_count, c.right_count, c.contribution
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::split_arrays;
+
+    #[test]
+    fn test_report_on_example() {
+        let (left, right) = split_arrays(include_str!("example.txt")).unwrap();
+        let report = Report::new(&left, &right, 2, 3);
+
+        assert_eq!(report.total_distance, 11);
+        assert_eq!(report.similarity, 31);
+        assert_eq!(
+            report.pairs.iter().map(|p| p.distance).collect::<Vec<_>>(),
+            vec![2, 1, 0, 1, 2, 5]
+        );
+        assert_eq!(
+            report.histogram,
+            vec![
+                Bucket {
+                    from: 0,
+                    to: 1,
+                    pairs: 3
+                },
+                Bucket {
+                    from: 2,
+                    to: 3,
+                    pairs: 2
+                },
+                Bucket {
+                    from: 4,
+                    to: 5,
+                    pairs: 1
+                },
+            ]
+        );
+        assert_eq!(
+            report.top_pairs.iter().map(|p| p.rank).collect::<Vec<_>>(),
+            vec![6, 1]
+        );
+        assert_eq!(
+            report.top_contributors[0],
+            Contributor {
+                id: 3,
+                left_count: 3,
+                right_count: 3,
+                contribution: 27,
+            }
+        );
+        assert!(report.to_csv().contains(
+            "\n# top_contributors\nid,left_count,right_count,contribution\n3,3,3,27\n4,1,1,4\n"
+        ));
+    }
+}
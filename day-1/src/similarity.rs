@@ -0,0 +1,154 @@
+//! Ways of scoring how alike two ID lists are. Part 2 uses [`Weighted`].
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::{calculate_weighted_sum, count_occurrences};
+
+/// A similarity score: an exact count for the counting metrics, a ratio for the rest.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Score {
+    Count(i128),
+    Ratio(f64),
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Score::Count(count) => write!(f, "{}", count),
+            Score::Ratio(ratio) => write!(f, "{:.6}", ratio),
+        }
+    }
+}
+
+pub trait SimilarityMetric {
+    fn name(&self) -> &'static str;
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Score;
+}
+
+/// Each left ID times how often it appears on the right, as in part 2.
+pub struct Weighted;
+
+impl SimilarityMetric for Weighted {
+    fn name(&self) -> &'static str {
+        "weighted"
+    }
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Score {
+        Score::Count(calculate_weighted_sum(left, right))
+    }
+}
+
+/// How many IDs the lists share, counting repeats: an ID in the left list twice and the
+/// right list three times counts twice.
+pub struct MultisetIntersection;
+
+impl SimilarityMetric for MultisetIntersection {
+    fn name(&self) -> &'static str {
+        "intersection"
+    }
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Score {
+        let lookup = count_occurrences(right);
+        let shared = count_occurrences(left)
+            .into_iter()
+            .map(|(id, count)| count.min(lookup.get(&id).copied().unwrap_or(0)) as i128)
+            .sum();
+        Score::Count(shared)
+    }
+}
+
+/// Distinct IDs in both lists over distinct IDs in either. Two empty lists score 1.
+pub struct Jaccard;
+
+impl SimilarityMetric for Jaccard {
+    fn name(&self) -> &'static str {
+        "jaccard"
+    }
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Score {
+        let left: HashSet<i64> = left.iter().copied().collect();
+        let right: HashSet<i64> = right.iter().copied().collect();
+        let union = left.union(&right).count();
+        if union == 0 {
+            return Score::Ratio(1.0);
+        }
+        Score::Ratio(left.intersection(&right).count() as f64 / union as f64)
+    }
+}
+
+/// The weighted score taken in both directions, but counting each distinct ID once per side:
+/// every ID times how often it appears in the other list, for the distinct IDs of both. A
+/// shared ID contributes `id × (left count + right count)`, so repeats on one side add to
+/// the score rather than multiply it as they do in [`Weighted`].
+pub struct SymmetricWeighted;
+
+impl SimilarityMetric for SymmetricWeighted {
+    fn name(&self) -> &'static str {
+        "symmetric"
+    }
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Score {
+        let (left, right) = (count_occurrences(left), count_occurrences(right));
+        let score = left
+            .iter()
+            .filter_map(|(id, &left_count)| {
+                let right_count = *right.get(id)?;
+                Some(*id as i128 * (left_count + right_count) as i128)
+            })
+            .sum();
+        Score::Count(score)
+    }
+}
+
+/// Every metric, in the order the CLI lists them.
+pub const METRICS: [&dyn SimilarityMetric; 4] = [
+    &Weighted,
+    &MultisetIntersection,
+    &Jaccard,
+    &SymmetricWeighted,
+];
+
+/// The metric called `name`.
+pub fn metric(name: &str) -> Option<&'static dyn SimilarityMetric> {
+    METRICS.into_iter().find(|metric| metric.name() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::split_arrays;
+
+    #[test]
+    fn test_metrics_on_example() {
+        let (left, right) = split_arrays(include_str!("example.txt")).unwrap();
+
+        assert_eq!(Weighted.score(&left, &right), Score::Count(31));
+        assert_eq!(MultisetIntersection.score(&left, &right), Score::Count(4));
+        // {1, 2, 3, 4} and {3, 4, 5, 9} share 2 of 6 distinct IDs
+        assert_eq!(Jaccard.score(&left, &right), Score::Ratio(2.0 / 6.0));
+        // 3 is in each list three times and 4 once: 3 × (3 + 3) + 4 × (1 + 1)
+        assert_eq!(SymmetricWeighted.score(&left, &right), Score::Count(26));
+    }
+
+    #[test]
+    fn test_metrics_ignore_list_order() {
+        let left = [5, 5, 7, 1];
+        let right = [5, 7, 7, 7, 2];
+
+        for metric in METRICS {
+            assert_eq!(
+                metric.score(&left, &right),
+                metric.score(&right, &left),
+                "{}",
+                metric.name()
+            );
+        }
+        // 5 × (2 + 1) + 7 × (1 + 3)
+        assert_eq!(
+            metric("symmetric").unwrap().score(&left, &right),
+            Score::Count(43)
+        );
+    }
+}
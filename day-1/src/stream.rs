@@ -0,0 +1,300 @@
+//! Both totals for location lists too big to hold in memory.
+//!
+//! Pairs are read a line at a time. Each list goes through an [`ExternalSorter`], which keeps
+//! up to `run_size` IDs in memory and writes sorted runs to temporary files beyond that. The
+//! totals then come from walking the two sorted lists side by side.
+//!
+//! Each run is an open file while it is being merged, so runs are merged at most
+//! [`MAX_MERGE`] at a time, writing the result of each batch out as a longer run, until few
+//! enough are left to read back together.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use aoc_core::{Result, Span};
+
+use crate::parse_pair;
+
+static NEXT_SORTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The most runs merged at once, which bounds how many files each sorter holds open.
+pub const MAX_MERGE: usize = 64;
+
+/// Sorts a stream of IDs while holding at most `run_size` of them in memory.
+pub struct ExternalSorter {
+    run_size: usize,
+    buffer: Vec<i64>,
+    dir: PathBuf,
+    runs: Vec<PathBuf>,
+    /// Names the next run file; runs merged into longer ones free their slot in `runs`.
+    next_run: usize,
+}
+
+impl ExternalSorter {
+    pub fn new(run_size: usize) -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "day-1-sort-{}-{}",
+            std::process::id(),
+            NEXT_SORTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        Self {
+            run_size: run_size.max(1),
+            buffer: Vec::new(),
+            dir,
+            runs: Vec::new(),
+            next_run: 0,
+        }
+    }
+
+    pub fn push(&mut self, id: i64) -> io::Result<()> {
+        self.buffer.push(id);
+        if self.buffer.len() >= self.run_size {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    /// How many sorted runs have been written to disk.
+    pub fn spilled_runs(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// A path for a new run file.
+    fn new_run(&mut self) -> io::Result<PathBuf> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(format!("run-{}", self.next_run));
+        self.next_run += 1;
+        Ok(path)
+    }
+
+    /// Sorts the buffer and writes it out as the next run.
+    fn spill(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+        let path = self.new_run()?;
+        let mut writer = BufWriter::new(File::create(&path)?);
+        for id in self.buffer.drain(..) {
+            writer.write_all(&id.to_le_bytes())?;
+        }
+        writer.flush()?;
+        self.runs.push(path);
+        Ok(())
+    }
+
+    /// Merges the oldest [`MAX_MERGE`] runs into one until no more than that many are left.
+    fn compact(&mut self) -> io::Result<()> {
+        while self.runs.len() > MAX_MERGE {
+            let batch: Vec<PathBuf> = self.runs.drain(..MAX_MERGE).collect();
+            let path = self.new_run()?;
+            let mut writer = BufWriter::new(File::create(&path)?);
+            let mut ids = SortedIds::merge(&[], &batch)?;
+            while let Some(id) = ids.next_id()? {
+                writer.write_all(&id.to_le_bytes())?;
+            }
+            writer.flush()?;
+            drop(ids);
+            for run in batch {
+                fs::remove_file(run)?;
+            }
+            self.runs.push(path);
+        }
+        Ok(())
+    }
+
+    /// Reads back every pushed ID in ascending order, holding at most [`MAX_MERGE`] files
+    /// open. Can be called more than once.
+    pub fn sorted(&mut self) -> io::Result<SortedIds<'_>> {
+        if !self.runs.is_empty() && !self.buffer.is_empty() {
+            self.spill()?;
+        }
+        self.compact()?;
+        self.buffer.sort_unstable();
+        SortedIds::merge(&self.buffer, &self.runs)
+    }
+}
+
+impl Drop for ExternalSorter {
+    fn drop(&mut self) {
+        if !self.runs.is_empty() {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+}
+
+fn read_id(reader: &mut impl Read) -> io::Result<Option<i64>> {
+    let mut bytes = [0; 8];
+    match reader.read_exact(&mut bytes) {
+        Ok(()) => Ok(Some(i64::from_le_bytes(bytes))),
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// The IDs from an [`ExternalSorter`], smallest first, merged from its runs.
+pub struct SortedIds<'a> {
+    memory: std::slice::Iter<'a, i64>,
+    readers: Vec<BufReader<File>>,
+    heap: BinaryHeap<Reverse<(i64, usize)>>,
+}
+
+impl<'a> SortedIds<'a> {
+    /// The IDs in `memory`, which must be sorted, or else merged from the run files.
+    fn merge(memory: &'a [i64], runs: &[PathBuf]) -> io::Result<Self> {
+        let mut readers = Vec::new();
+        let mut heap = BinaryHeap::new();
+        for (run, path) in runs.iter().enumerate() {
+            let mut reader = BufReader::new(File::open(path)?);
+            if let Some(id) = read_id(&mut reader)? {
+                heap.push(Reverse((id, run)));
+            }
+            readers.push(reader);
+        }
+
+        Ok(SortedIds {
+            memory: memory.iter(),
+            readers,
+            heap,
+        })
+    }
+
+    pub fn next_id(&mut self) -> io::Result<Option<i64>> {
+        if self.readers.is_empty() {
+            return Ok(self.memory.next().copied());
+        }
+        let Some(Reverse((id, run))) = self.heap.pop() else {
+            return Ok(None);
+        };
+        if let Some(next) = read_id(&mut self.readers[run])? {
+            self.heap.push(Reverse((next, run)));
+        }
+        Ok(Some(id))
+    }
+}
+
+/// Both day-1 totals for the pairs in `reader`, keeping at most `run_size` IDs from each list
+/// in memory at once.
+pub fn stream_totals(reader: impl BufRead, run_size: usize) -> Result<(i128, i128)> {
+    let mut left = ExternalSorter::new(run_size);
+    let mut right = ExternalSorter::new(run_size);
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let span = Span {
+            line: index + 1,
+            column: 1,
+            text: &line,
+        };
+        if span.is_blank() {
+            continue;
+        }
+        let (left_id, right_id) = parse_pair(span)?;
+        left.push(left_id)?;
+        right.push(right_id)?;
+    }
+
+    // same as calculate_sum_of_differences: pair the lists up smallest to smallest
+    let mut distance = 0;
+    {
+        // scoped so these runs are closed before the second pass opens them again
+        let (mut left_ids, mut right_ids) = (left.sorted()?, right.sorted()?);
+        while let (Some(a), Some(b)) = (left_ids.next_id()?, right_ids.next_id()?) {
+            distance += a.abs_diff(b) as i128;
+        }
+    }
+
+    // same as calculate_weighted_sum: both lists are sorted, so each left ID's count in the
+    // right list is found by advancing through the right list once
+    let mut similarity = 0;
+    let (mut left_ids, mut right_ids) = (left.sorted()?, right.sorted()?);
+    let mut right_next = right_ids.next_id()?;
+    let mut current: Option<(i64, i64)> = None;
+    while let Some(id) = left_ids.next_id()? {
+        let count = match current {
+            Some((value, count)) if value == id => count,
+            _ => {
+                let mut count = 0;
+                while let Some(right_id) = right_next.filter(|&right_id| right_id <= id) {
+                    if right_id == id {
+                        count += 1;
+                    }
+                    right_next = right_ids.next_id()?;
+                }
+                current = Some((id, count));
+                count
+            }
+        };
+        similarity += id as i128 * count as i128;
+    }
+
+    Ok((distance, similarity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day1;
+    use aoc_core::generate::{seeded, Generate};
+    use aoc_core::{Answer, Solution};
+
+    #[test]
+    fn test_sorter_merges_spilled_runs() {
+        let mut sorter = ExternalSorter::new(3);
+        for id in [9, -4, 7, 7, 0, 12, 3, -4, 5, 1] {
+            sorter.push(id).unwrap();
+        }
+        assert_eq!(sorter.spilled_runs(), 3);
+
+        let mut sorted = Vec::new();
+        let mut ids = sorter.sorted().unwrap();
+        while let Some(id) = ids.next_id().unwrap() {
+            sorted.push(id);
+        }
+        assert_eq!(sorted, vec![-4, -4, 0, 1, 3, 5, 7, 7, 9, 12]);
+
+        let dir = sorter.dir.clone();
+        drop(sorter);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_sorter_merges_many_runs_in_batches() {
+        let mut sorter = ExternalSorter::new(2);
+        let ids: Vec<i64> = (0..1000).map(|i| (i * 7919) % 1000 - 500).collect();
+        for &id in &ids {
+            sorter.push(id).unwrap();
+        }
+        assert_eq!(sorter.spilled_runs(), 500);
+
+        let mut sorted = Vec::new();
+        let mut merged = sorter.sorted().unwrap();
+        assert!(merged.readers.len() <= MAX_MERGE);
+        while let Some(id) = merged.next_id().unwrap() {
+            sorted.push(id);
+        }
+        drop(merged);
+        assert!(sorter.spilled_runs() <= MAX_MERGE);
+
+        let mut expected = ids;
+        expected.sort();
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn test_stream_totals_match_in_memory_totals() {
+        for seed in 0..20 {
+            let input = Day1::generate(&mut seeded(seed));
+            let lists = Day1::parse(&input).unwrap();
+            let (distance, similarity) = stream_totals(input.as_bytes(), 4).unwrap();
+
+            assert_eq!(Answer::from(distance), Day1::part1(&lists), "seed {}", seed);
+            assert_eq!(
+                Answer::from(similarity),
+                Day1::part2(&lists),
+                "seed {}",
+                seed
+            );
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{Answer, Coord, Direction, Generate, Grid, ParseResult, Solution};
+use std::collections::HashSet;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let lines: Vec<&str> = input.lines().map(str::trim).collect();
+        Grid::parse(&lines.join("\n"))
+    }
+
+    /// Sums the trailhead scores: how many distinct 9s each 0 can reach.
+    fn part1(grid: &Self::Input) -> Answer {
+        let mut finder = NumberPathFinder::new(grid);
+        finder.find_all_paths();
+        finder.unique_path_count().into()
+    }
+
+    /// Sums the trailhead ratings: how many distinct routes lead from each 0 to a 9.
+    fn part2(grid: &Self::Input) -> Answer {
+        let mut finder = NumberPathFinder::new(grid);
+        finder.find_all_paths();
+        finder.total_path_count().into()
+    }
+}
+
+impl Generate for Day10 {
+    /// Random heights with a few hiking trails carved in as random walks from 0 up to 9.
+    fn generate(rng: &mut StdRng) -> String {
+        let (height, width) = (rng.random_range(1..12), rng.random_range(1..12));
+        let mut map = Grid::new(height, width, '0');
+        for coord in map.coords().collect::<Vec<_>>() {
+            map[coord] = char::from_digit(rng.random_range(0..10), 10).unwrap();
+        }
+
+        for _ in 0..rng.random_range(0..6) {
+            let mut coord = Coord::new(rng.random_range(0..height), rng.random_range(0..width));
+            for digit in 0..10 {
+                map[coord] = char::from_digit(digit, 10).unwrap();
+                let direction = Direction::ORTHOGONAL[rng.random_range(0..4)];
+                match map.offset(coord, direction) {
+                    Some(next) => coord = next,
+                    None => break,
+                }
+            }
+        }
+        map.to_string()
+    }
+}
+
+/// A trail from a `0` trailhead to the `9` it ends on.
+#[derive(PartialEq, Eq, Hash)]
+struct Path {
+    start: Coord,
+    end: Coord,
+}
+
+struct NumberPathFinder<'a> {
+    grid: &'a Grid<char>,
+    unique_paths: HashSet<Path>,
+    total_paths: usize,
+}
+
+impl<'a> NumberPathFinder<'a> {
+    fn new(grid: &'a Grid<char>) -> Self {
+        Self {
+            grid,
+            unique_paths: HashSet::new(),
+            total_paths: 0,
+        }
+    }
+
+    fn find_all_paths(&mut self) {
+        // Start DFS from each zero
+        for zero_pos in self.grid.find_all(&'0') {
+            self.dfs(zero_pos, zero_pos, '0');
+        }
+    }
+
+    fn dfs(&mut self, start: Coord, current: Coord, current_digit: char) {
+        // If we've reached '9', we've found a complete path
+        if current_digit == '9' {
+            self.unique_paths.insert(Path {
+                start,
+                end: current,
+            });
+            self.total_paths += 1;
+            return;
+        }
+
+        // Calculate next digit
+        let next_digit = char::from_digit((current_digit as u8 - b'0' + 1) as u32, 10).unwrap();
+
+        // Check all adjacent cells for next digit
+        let grid = self.grid;
+        for next in grid.neighbours4(current) {
+            if grid[next] == next_digit {
+                self.dfs(start, next, next_digit);
+            }
+        }
+    }
+
+    fn unique_path_count(&self) -> usize {
+        self.unique_paths.len()
+    }
+
+    fn total_path_count(&self) -> usize {
+        self.total_paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::check_against_reference;
+
+    /// Scores trailheads with a flood fill and rates them by counting routes height by height,
+    /// from the 9s down.
+    fn reference(input: &str) -> (Answer, Answer) {
+        let map = Day10::parse(input).unwrap();
+        let height = |coord: Coord| map[coord].to_digit(10);
+        let uphill = |coord: Coord| {
+            map.neighbours4(coord).filter(move |&next| {
+                height(next).is_some() && height(next) == height(coord).map(|h| h + 1)
+            })
+        };
+
+        let mut score = 0;
+        for trailhead in map.find_all(&'0') {
+            let mut reached = HashSet::from([trailhead]);
+            let mut frontier = vec![trailhead];
+            while let Some(coord) = frontier.pop() {
+                for next in uphill(coord) {
+                    if reached.insert(next) {
+                        frontier.push(next);
+                    }
+                }
+            }
+            score += reached.iter().filter(|&&coord| map[coord] == '9').count();
+        }
+
+        let mut routes = Grid::new(map.height(), map.width(), 0usize);
+        for level in (0..=9).rev() {
+            for coord in map.coords().filter(|&coord| height(coord) == Some(level)) {
+                routes[coord] = if level == 9 {
+                    1
+                } else {
+                    uphill(coord).map(|next| routes[next]).sum()
+                };
+            }
+        }
+        let rating: usize = map.find_all(&'0').iter().map(|&coord| routes[coord]).sum();
+
+        (score.into(), rating.into())
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day10>(100, reference);
+    }
+}
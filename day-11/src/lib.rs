@@ -0,0 +1,145 @@
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{numbered_lines, Answer, Generate, ParseResult, Solution};
+use std::collections::HashMap;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+  type Input = Vec<usize>;
+
+  fn parse(file_data: &str) -> ParseResult<Self::Input> {
+    let mut stone_data = Vec::new();
+    for line in numbered_lines(file_data) {
+      for s in line.fields() {
+        stone_data.push(s.parse::<usize>("a stone number")?);
+      }
+    }
+    Ok(stone_data)
+  }
+
+  /// Counts the stones after 25 blinks.
+  fn part1(stone_data: &Self::Input) -> Answer {
+    count_stones_after_blinks(stone_data, 25).into()
+  }
+
+  /// Counts the stones after 75 blinks.
+  fn part2(stone_data: &Self::Input) -> Answer {
+    count_stones_after_blinks(stone_data, 75).into()
+  }
+}
+
+impl Generate for Day11 {
+  /// A short row of engraved stones, zeros included.
+  fn generate(rng: &mut StdRng) -> String {
+    let stones: Vec<String> = (0..rng.random_range(1..5))
+      .map(|_| rng.random_range(0..10000).to_string())
+      .collect();
+    stones.join(" ") + "\n"
+  }
+}
+
+fn count_stones_after_blinks(stone_data: &[usize], blinks: usize) -> usize {
+  let stones_map = create_stones_map(stone_data);
+  let new_stones_map = iterate_stones_map(&stones_map, 0, blinks);
+  new_stones_map.iter().fold(0, |acc, (_, value)| acc + value)
+}
+
+fn create_stones_map(stone_data: &[usize]) -> HashMap<usize, usize> {
+  let mut stones_map: HashMap<usize, usize> = HashMap::new();
+  stone_data.iter().for_each(|stone_number| {
+    insert_key_or_increment_value(&mut stones_map, *stone_number, 1);
+  });
+  stones_map
+}
+
+fn insert_key_or_increment_value(
+  updated_stone_data: &mut HashMap<usize, usize>,
+  key: usize,
+  value: usize,
+) {
+  *updated_stone_data.entry(key).or_insert(0) += value;
+}
+
+fn iterate_stones_map(
+  stones_map: &HashMap<usize, usize>,
+  counter: usize,
+  blinks: usize,
+) -> HashMap<usize, usize> {
+  if counter >= blinks {
+    return stones_map.clone();
+  }
+  let mut new_map = HashMap::new();
+
+  for (key, value) in stones_map.iter() {
+    if *key == 0 {
+      insert_key_or_increment_value(&mut new_map, 1, *value);
+    } else if key.to_string().len() % 2 == 0 {
+      let stone_number_string = key.to_string();
+      let half = stone_number_string.len() / 2;
+      let left_stone = &stone_number_string[..half];
+      let right_stone = &stone_number_string[half..];
+
+      insert_key_or_increment_value(
+        &mut new_map,
+        left_stone.parse::<usize>().unwrap(),
+        *value,
+      );
+      insert_key_or_increment_value(
+        &mut new_map,
+        right_stone.parse::<usize>().unwrap(),
+        *value,
+      );
+    } else {
+      insert_key_or_increment_value(&mut new_map, key * 2024, *value);
+    }
+  }
+  iterate_stones_map(&new_map, counter + 1, blinks)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use aoc_core::generate::{seeded, Generate};
+
+  /// Applies the rules to every stone in the row, one blink at a time.
+  fn blink_one_by_one(stones: &[usize], blinks: usize) -> usize {
+    let mut stones = stones.to_vec();
+    for _ in 0..blinks {
+      stones = stones
+        .iter()
+        .flat_map(|&stone| {
+          let digits = stone.to_string();
+          if stone == 0 {
+            vec![1]
+          } else if digits.len() % 2 == 0 {
+            let (left, right) = digits.split_at(digits.len() / 2);
+            vec![left.parse().unwrap(), right.parse().unwrap()]
+          } else {
+            vec![stone * 2024]
+          }
+        })
+        .collect();
+    }
+    stones.len()
+  }
+
+  /// 75 blinks is far too many to do stone by stone, so this checks the counting solver
+  /// against the row-by-row one for up to 25 blinks.
+  #[test]
+  fn test_matches_reference_on_generated_inputs() {
+    for seed in 0..20 {
+      let input = Day11::generate(&mut seeded(seed));
+      let stones = Day11::parse(&input).unwrap();
+      for blinks in [0, 1, 6, 25] {
+        assert_eq!(
+          count_stones_after_blinks(&stones, blinks),
+          blink_one_by_one(&stones, blinks),
+          "seed {} after {} blinks on {:?}",
+          seed,
+          blinks,
+          input
+        );
+      }
+    }
+  }
+}
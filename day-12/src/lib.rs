@@ -0,0 +1,203 @@
+//! Garden plot pricing. The binary in `main.rs` animates the same flood fill in a window;
+//! these functions compute the answers directly.
+
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{Answer, Coord, Direction, Generate, Grid, ParseResult, Solution};
+use std::collections::VecDeque;
+
+/// A connected group of plots that share the same plant letter.
+#[derive(Debug)]
+pub struct Region {
+    pub letter: char,
+    pub coords: Vec<Coord>,
+}
+
+/// Flood fills the garden into its regions.
+pub fn find_regions(grid: &Grid<char>) -> Vec<Region> {
+    let mut visited = Grid::new(grid.height(), grid.width(), false);
+    let mut regions = Vec::new();
+
+    for (start, &letter) in grid.iter() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+
+        let mut coords = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(coord) = queue.pop_front() {
+            coords.push(coord);
+            for next in grid.neighbours4(coord) {
+                if grid[next] == letter && !visited[next] {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        regions.push(Region { letter, coords });
+    }
+
+    regions
+}
+
+/// Counts the fence segments around a region: one for every edge facing another plant.
+pub fn perimeter(grid: &Grid<char>, region: &Region) -> usize {
+    region
+        .coords
+        .iter()
+        .map(|&coord| {
+            Direction::ORTHOGONAL
+                .iter()
+                .filter(|&&direction| {
+                    grid.offset(coord, direction).map(|next| grid[next]) != Some(region.letter)
+                })
+                .count()
+        })
+        .sum()
+}
+
+/// Counts the straight sides of a region, which is the same as counting its corners.
+pub fn sides(grid: &Grid<char>, region: &Region) -> usize {
+    let same = |coord: Coord, row_step: isize, col_step: isize| {
+        grid.offset_by(coord, row_step, col_step)
+            .is_some_and(|next| grid[next] == region.letter)
+    };
+
+    region
+        .coords
+        .iter()
+        .map(|&coord| {
+            Direction::ORTHOGONAL
+                .iter()
+                .filter(|&&direction| {
+                    let (dr1, dc1) = direction.delta();
+                    let (dr2, dc2) = direction.turn_right().delta();
+                    let first = same(coord, dr1, dc1);
+                    let second = same(coord, dr2, dc2);
+                    let diagonal = same(coord, dr1 + dr2, dc1 + dc2);
+                    // outside corner, or inside corner where both neighbours match but the diagonal does not
+                    (!first && !second) || (first && second && !diagonal)
+                })
+                .count()
+        })
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        Grid::parse(input)
+    }
+
+    /// Sums area × perimeter over every region.
+    fn part1(grid: &Self::Input) -> Answer {
+        find_regions(grid)
+            .iter()
+            .map(|region| region.coords.len() * perimeter(grid, region))
+            .sum::<usize>()
+            .into()
+    }
+
+    /// Sums area × number of sides over every region.
+    fn part2(grid: &Self::Input) -> Answer {
+        find_regions(grid)
+            .iter()
+            .map(|region| region.coords.len() * sides(grid, region))
+            .sum::<usize>()
+            .into()
+    }
+}
+
+impl Generate for Day12 {
+    /// A garden of a few plant types. Each plot copies a neighbour's plant more often than not,
+    /// so regions grow into odd shapes, some with holes in them.
+    fn generate(rng: &mut StdRng) -> String {
+        let (height, width) = (rng.random_range(1..15), rng.random_range(1..15));
+        let plants = &['A', 'B', 'C', 'D', 'E'][..rng.random_range(1..=5)];
+        let mut garden = Grid::new(height, width, 'A');
+        for coord in garden.coords().collect::<Vec<_>>() {
+            let up = garden.offset(coord, Direction::Up);
+            let left = garden.offset(coord, Direction::Left);
+            garden[coord] = match (rng.random_range(0..4), up, left) {
+                (0, Some(up), _) => garden[up],
+                (1, _, Some(left)) => garden[left],
+                _ => plants[rng.random_range(0..plants.len())],
+            };
+        }
+        garden.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::check_against_reference;
+    use std::collections::HashSet;
+
+    /// Labels regions by repeatedly merging neighbouring labels, and counts sides by looking
+    /// for fence pieces that do not continue one further along.
+    fn reference(input: &str) -> (Answer, Answer) {
+        let garden = Day12::parse(input).unwrap();
+        let coords: Vec<Coord> = garden.coords().collect();
+        let mut label = Grid::new(garden.height(), garden.width(), 0);
+        for (index, &coord) in coords.iter().enumerate() {
+            label[coord] = index;
+        }
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &coord in &coords {
+                for next in garden.neighbours4(coord) {
+                    if garden[next] == garden[coord] && label[next] < label[coord] {
+                        label[coord] = label[next];
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        let fenced = |coord: Coord, direction: Direction| {
+            garden
+                .offset(coord, direction)
+                .is_none_or(|next| label[next] != label[coord])
+        };
+        let (mut part1, mut part2) = (0, 0);
+        for region in coords
+            .iter()
+            .map(|&coord| label[coord])
+            .collect::<HashSet<_>>()
+        {
+            let plots: Vec<Coord> = coords
+                .iter()
+                .copied()
+                .filter(|&coord| label[coord] == region)
+                .collect();
+            let fences: Vec<(Coord, Direction)> = plots
+                .iter()
+                .flat_map(|&coord| Direction::ORTHOGONAL.map(|direction| (coord, direction)))
+                .filter(|&(coord, direction)| fenced(coord, direction))
+                .collect();
+            let sides = fences
+                .iter()
+                .filter(
+                    |&&(coord, direction)| match garden.offset(coord, direction.turn_right()) {
+                        Some(along) => label[along] != region || !fenced(along, direction),
+                        None => true,
+                    },
+                )
+                .count();
+            part1 += plots.len() * fences.len();
+            part2 += plots.len() * sides;
+        }
+        (part1.into(), part2.into())
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day12>(100, reference);
+    }
+}
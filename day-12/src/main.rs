@@ -22,7 +22,7 @@ enum TimeUnit {
 #[derive(Clone, Debug)]
 struct GridState {
     letter_areas: Vec<LetterArea>, // Changed from HashMap to Vec to store multiple groups
-    current_area: Option<usize>,   // Now stores group_id instead of letter as letters are not unique
+    current_area: Option<usize>, // Now stores group_id instead of letter as letters are not unique
     step_count: usize,
     next_group_id: usize,                  // Added to generate unique group IDs
     current_fence_pos: Option<(i32, i32)>, // Track current fence position
@@ -133,12 +133,12 @@ impl MyApp {
     }
 
     fn generate_distinct_color(&self) -> Color32 {
-        let mut rng = rand::thread_rng();
+        let mut rng = rand::rng();
         // Use more saturated base values to avoid light colors
         Color32::from_rgb(
-            rng.gen_range(50..200), // Lower max to avoid light colors
-            rng.gen_range(50..200),
-            rng.gen_range(50..200),
+            rng.random_range(50..200), // Lower max to avoid light colors
+            rng.random_range(50..200),
+            rng.random_range(50..200),
         )
     }
 
@@ -282,10 +282,6 @@ impl MyApp {
                                 area.fences.push((row, col, current_dir));
                                 self.state.step_count += 1;
 
-                                let area_size = area.coords.len();
-                                let fence_count = area.fences.len();
-                                let area_score = area_size * fence_count;
-
                                 // Update total score considering all areas
                                 self.state.total_score = self.calculate_total_score();
                             }
@@ -644,12 +640,24 @@ fn main() -> eframe::Result {
     std::env::set_var("WINIT_UNIX_BACKEND", "x11");
     env_logger::init();
 
-    let grid = load_grid_from_file("src/data.txt");
+    let grid = match aoc_core::load_grid_from_file("src/data.txt") {
+        Ok(grid) if grid.first().is_some_and(|row| !row.is_empty()) => grid,
+        // Fall back to a placeholder grid if the file was empty
+        Ok(_) => vec![
+            vec!['A', 'B', 'C'],
+            vec!['D', 'E', 'F'],
+            vec!['G', 'H', 'I'],
+        ],
+        Err(_) => vec![
+            vec!['1', '2', '3'],
+            vec!['4', '5', '6'],
+            vec!['7', '8', '9'],
+        ],
+    };
     println!("Loaded grid: {:?}", grid); // Debug print
     let app = MyApp::new(grid.clone());
 
     let min_cell_size = 40.0; // Try a larger minimum cell size
-    let padding = 100.0; // Add more padding
 
     let width = (grid[0].len() as f32 * min_cell_size) + 240.0;
     let height = (grid.len() as f32 * min_cell_size) + 80.0;
@@ -674,27 +682,3 @@ fn main() -> eframe::Result {
         }),
     )
 }
-
-fn load_grid_from_file(path: &str) -> Vec<Vec<char>> {
-    use std::fs;
-    match fs::read_to_string(path) {
-        Ok(content) => {
-            let grid: Vec<Vec<char>> = content.lines().map(|line| line.chars().collect()).collect();
-            println!("Loaded grid: {:?}", grid); // Debug print
-            if grid.is_empty() || grid[0].is_empty() {
-                // Return default grid if file was empty
-                return vec![
-                    vec!['A', 'B', 'C'],
-                    vec!['D', 'E', 'F'],
-                    vec!['G', 'H', 'I'],
-                ];
-            }
-            grid
-        }
-        Err(_) => vec![
-            vec!['1', '2', '3'],
-            vec!['4', '5', '6'],
-            vec!['7', '8', '9'],
-        ],
-    }
-}
@@ -0,0 +1,204 @@
+//! Why each report is or isn't safe, line by line.
+
+use std::fmt;
+
+use aoc_core::{numbered_lines, ParseResult};
+use serde::Serialize;
+
+use crate::monitor::StreamState;
+use crate::parse_report;
+use crate::policy::SafetyPolicy;
+use crate::removals::minimum_removals;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Classification {
+    Safe,
+    /// Unsafe as it stands, but the Problem Dampener can fix it.
+    DampenedSafe,
+    Unsafe,
+}
+
+/// The rule a step between two neighbouring levels broke.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Rule {
+    StepTooLarge,
+    StepTooSmall,
+    ZeroStep,
+    /// The step goes the other way from the earlier ones.
+    DirectionChange,
+    /// The step goes against the trend the policy requires.
+    WrongDirection,
+}
+
+/// The first step that broke a rule, between the levels at `first` and `second`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    pub first: usize,
+    pub second: usize,
+    pub rule: Rule,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RemovedLevel {
+    pub index: usize,
+    pub level: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    pub line: usize,
+    pub levels: Vec<i32>,
+    pub classification: Classification,
+    /// The first broken rule in the report as it stands.
+    pub violation: Option<Violation>,
+    /// The levels the dampener drops to make a dampened-safe report safe.
+    pub removed: Vec<RemovedLevel>,
+}
+
+/// The first step in `numbers` that `policy` doesn't allow.
+pub fn first_violation(numbers: &[i32], policy: &SafetyPolicy) -> Option<Violation> {
+    let mut state = StreamState::default();
+    numbers.iter().find_map(|&level| state.push(level, policy))
+}
+
+/// Explains one report.
+pub fn explain_report(line: usize, numbers: &[i32], policy: &SafetyPolicy) -> Explanation {
+    let violation = first_violation(numbers, policy);
+    let mut removed = Vec::new();
+    let classification = if violation.is_none() {
+        Classification::Safe
+    } else {
+        let dropped = minimum_removals(numbers, policy);
+        if dropped.len() <= policy.max_removals {
+            removed = dropped
+                .into_iter()
+                .map(|index| RemovedLevel {
+                    index,
+                    level: numbers[index],
+                })
+                .collect();
+            Classification::DampenedSafe
+        } else {
+            Classification::Unsafe
+        }
+    };
+
+    Explanation {
+        line,
+        levels: numbers.to_vec(),
+        classification,
+        violation,
+        removed,
+    }
+}
+
+/// Explains every report in the input.
+pub fn explain(contents: &str, policy: &SafetyPolicy) -> ParseResult<Vec<Explanation>> {
+    numbered_lines(contents)
+        .filter(|line| !line.is_blank())
+        .map(|line| Ok(explain_report(line.line, &parse_report(line)?, policy)))
+        .collect()
+}
+
+impl fmt::Display for Classification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Classification::Safe => write!(f, "safe"),
+            Classification::DampenedSafe => write!(f, "dampened-safe"),
+            Classification::Unsafe => write!(f, "unsafe"),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::StepTooLarge => write!(f, "step too large"),
+            Rule::StepTooSmall => write!(f, "step too small"),
+            Rule::ZeroStep => write!(f, "zero step"),
+            Rule::DirectionChange => write!(f, "direction change"),
+            Rule::WrongDirection => write!(f, "wrong direction"),
+        }
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.classification)?;
+        if let Some(violation) = self.violation {
+            write!(
+                f,
+                ", {} at indices {}-{} ({} -> {})",
+                violation.rule,
+                violation.first,
+                violation.second,
+                self.levels[violation.first],
+                self.levels[violation.second]
+            )?;
+        }
+        if !self.removed.is_empty() {
+            let removed: Vec<String> = self
+                .removed
+                .iter()
+                .map(|removed| format!("index {} ({})", removed.index, removed.level))
+                .collect();
+            write!(f, ", fixed by removing {}", removed.join(" and "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::Trend;
+    use aoc_core::generate::{seeded, Rng};
+
+    #[test]
+    fn test_explain_example() {
+        let lines: Vec<String> = explain(include_str!("example.txt"), &SafetyPolicy::default())
+            .unwrap()
+            .iter()
+            .map(|explanation| explanation.to_string())
+            .collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "line 1: safe",
+                "line 2: unsafe, step too large at indices 1-2 (2 -> 7)",
+                "line 3: unsafe, step too large at indices 2-3 (6 -> 2)",
+                "line 4: dampened-safe, direction change at indices 1-2 (3 -> 2), fixed by removing index 2 (2)",
+                "line 5: dampened-safe, zero step at indices 2-3 (4 -> 4), fixed by removing index 3 (4)",
+                "line 6: safe",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_violation_agrees_with_policy() {
+        let mut rng = seeded(18);
+        for _ in 0..2000 {
+            let policy = SafetyPolicy {
+                min_step: rng.random_range(1..=2),
+                max_step: rng.random_range(2..=4),
+                allow_plateaus: rng.random_bool(0.5),
+                trend: [Trend::Any, Trend::Increasing, Trend::Decreasing][rng.random_range(0..3)],
+                max_removals: 1,
+            };
+            let numbers: Vec<i32> = (0..rng.random_range(1..8))
+                .map(|_| rng.random_range(0..10))
+                .collect();
+
+            assert_eq!(
+                first_violation(&numbers, &policy).is_none(),
+                policy.is_safe(&numbers),
+                "{:?} {:?}",
+                numbers,
+                policy
+            );
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{numbered_lines, Answer, Generate, ParseResult, Solution, Span};
+
+pub mod explain;
+pub mod monitor;
+pub mod policy;
+pub mod removals;
+
+use policy::SafetyPolicy;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_reports(input)
+    }
+
+    /// Counts the reports that are safe as they stand.
+    fn part1(reports: &Self::Input) -> Answer {
+        reports
+            .iter()
+            .filter(|numbers| is_safe_report(numbers))
+            .count()
+            .into()
+    }
+
+    /// Counts the reports that are safe, allowing the Problem Dampener to drop one level.
+    fn part2(reports: &Self::Input) -> Answer {
+        reports
+            .iter()
+            .filter(|numbers| is_safe_report(numbers) || is_safe_by_removing_one_element(numbers))
+            .count()
+            .into()
+    }
+}
+
+impl Generate for Day2 {
+    /// Reports that mostly climb or fall by 1 to 3, with the odd bad step thrown in.
+    fn generate(rng: &mut StdRng) -> String {
+        (0..rng.random_range(1..60))
+            .map(|_| {
+                let direction = if rng.random_bool(0.5) { 1 } else { -1 };
+                let mut level: i32 = rng.random_range(20..80);
+                let mut levels = vec![level];
+                for _ in 1..rng.random_range(1..9) {
+                    let step = if rng.random_bool(0.15) {
+                        rng.random_range(-5..=5)
+                    } else {
+                        direction * rng.random_range(1..=3)
+                    };
+                    level += step;
+                    levels.push(level);
+                }
+                let levels: Vec<String> = levels.iter().map(i32::to_string).collect();
+                levels.join(" ") + "\n"
+            })
+            .collect()
+    }
+}
+
+/// Splits each line into its levels.
+pub fn parse_reports(contents: &str) -> ParseResult<Vec<Vec<i32>>> {
+    numbered_lines(contents)
+        .filter(|line| !line.is_blank())
+        .map(parse_report)
+        .collect()
+}
+
+/// Reads the levels from one non-blank line.
+pub fn parse_report(line: Span) -> ParseResult<Vec<i32>> {
+    line.fields()
+        .map(|num| num.parse::<i32>("a level"))
+        .collect()
+}
+
+/// Whether the report is safe under the puzzle's rules: every step is 1 to 3 levels in the
+/// same direction.
+pub fn is_safe_report(numbers: &[i32]) -> bool {
+    SafetyPolicy::default().is_safe(numbers)
+}
+
+/// Whether dropping at most one level makes the report safe. Runs in linear time without
+/// copying the report.
+pub fn is_safe_by_removing_one_element(numbers: &[i32]) -> bool {
+    SafetyPolicy::default().is_safe_with_dampener(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::{check_against_reference, seeded};
+
+    fn safe(levels: &[i32]) -> bool {
+        let steps: Vec<i32> = levels.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        steps.iter().all(|step| (1..=3).contains(step))
+            || steps.iter().all(|step| (-3..=-1).contains(step))
+    }
+
+    fn reference(input: &str) -> (Answer, Answer) {
+        let reports = parse_reports(input).unwrap();
+        let part1 = reports.iter().filter(|levels| safe(levels)).count();
+        let part2 = reports
+            .iter()
+            .filter(|levels| {
+                (0..levels.len()).any(|skip| {
+                    let mut dampened = levels.to_vec();
+                    dampened.remove(skip);
+                    safe(&dampened)
+                })
+            })
+            .count();
+        (part1.into(), part2.into())
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day2>(100, reference);
+    }
+
+    /// The original dampener: try the report without each level in turn.
+    fn brute_force(numbers: &[i32]) -> bool {
+        (0..numbers.len()).any(|i| is_safe_report(&[&numbers[..i], &numbers[i + 1..]].concat()))
+    }
+
+    #[test]
+    fn test_dampener_matches_brute_force_on_random_reports() {
+        let mut rng = seeded(2);
+        for _ in 0..5000 {
+            let direction = if rng.random_bool(0.5) { 1 } else { -1 };
+            let mut level: i32 = rng.random_range(0..100);
+            let mut numbers = vec![level];
+            for _ in 1..rng.random_range(1..40) {
+                level += if rng.random_bool(0.05) {
+                    rng.random_range(-6..=6)
+                } else {
+                    direction * rng.random_range(1..=3)
+                };
+                numbers.push(level);
+            }
+
+            assert_eq!(
+                is_safe_by_removing_one_element(&numbers),
+                brute_force(&numbers),
+                "{:?}",
+                numbers
+            );
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Checks reports as their levels arrive, for following live logs instead of whole files.
+//!
+//! Each input line either starts with a stream name and adds its levels to that stream, or is
+//! made of levels alone and is checked as a complete report of its own:
+//!
+//! ```text
+//! pump-1 7 6
+//! pump-2 1
+//! pump-1 4
+//! 1 2 7 8 9
+//! ```
+//!
+//! A stream raises one alert, at the level that first makes it unsafe.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use aoc_core::{ParseResult, Span};
+
+use crate::explain::{Rule, Violation};
+use crate::policy::{SafetyPolicy, Trend};
+
+/// What `is_safe_report` tracks while it walks a report, kept between levels.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamState {
+    previous: Option<i32>,
+    /// 1 once the stream has gone up, -1 once it has gone down.
+    sign: Option<i32>,
+    len: usize,
+    violation: Option<Violation>,
+}
+
+impl StreamState {
+    /// Adds the next level, returning the broken rule if this level is the one that made the
+    /// stream unsafe. Once unsafe, a stream stays unsafe.
+    pub fn push(&mut self, level: i32, policy: &SafetyPolicy) -> Option<Violation> {
+        let second = self.len;
+        self.len += 1;
+        let previous = self.previous.replace(level)?;
+        if self.violation.is_some() {
+            return None;
+        }
+
+        let step = level as i64 - previous as i64;
+        let required = match policy.trend {
+            Trend::Any => self.sign,
+            Trend::Increasing => Some(1),
+            Trend::Decreasing => Some(-1),
+        };
+        let rule = if step == 0 {
+            (!policy.allow_plateaus).then_some(Rule::ZeroStep)
+        } else if step.abs() > policy.max_step as i64 {
+            Some(Rule::StepTooLarge)
+        } else if step.abs() < policy.min_step as i64 {
+            Some(Rule::StepTooSmall)
+        } else if required.is_some_and(|sign| sign as i64 != step.signum()) {
+            Some(match policy.trend {
+                Trend::Any => Rule::DirectionChange,
+                _ => Rule::WrongDirection,
+            })
+        } else {
+            self.sign = Some(step.signum() as i32);
+            None
+        };
+
+        self.violation = rule.map(|rule| Violation {
+            first: second - 1,
+            second,
+            rule,
+        });
+        self.violation
+    }
+
+    /// The rule the stream broke, if it is unsafe.
+    pub fn violation(&self) -> Option<Violation> {
+        self.violation
+    }
+
+    /// How many levels the stream has seen.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// A stream turning unsafe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alert {
+    /// The input line holding the offending level.
+    pub line: usize,
+    /// The stream's name, or `None` for a report given whole on one line.
+    pub stream: Option<String>,
+    pub violation: Violation,
+    pub from: i32,
+    pub to: i32,
+}
+
+impl fmt::Display for Alert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.stream {
+            Some(stream) => write!(f, "line {}: stream {:?} is unsafe", self.line, stream)?,
+            None => write!(f, "line {}: report is unsafe", self.line)?,
+        }
+        write!(
+            f,
+            ", {} at indices {}-{} ({} -> {})",
+            self.violation.rule, self.violation.first, self.violation.second, self.from, self.to
+        )
+    }
+}
+
+/// Every named stream seen so far.
+pub struct Monitor {
+    policy: SafetyPolicy,
+    streams: HashMap<String, StreamState>,
+}
+
+impl Monitor {
+    pub fn new(policy: SafetyPolicy) -> Self {
+        Self {
+            policy,
+            streams: HashMap::new(),
+        }
+    }
+
+    pub fn stream(&self, name: &str) -> Option<&StreamState> {
+        self.streams.get(name)
+    }
+
+    /// Feeds one input line, returning an alert if it made a stream unsafe.
+    pub fn feed(&mut self, line: Span) -> ParseResult<Option<Alert>> {
+        let mut fields = line.fields().peekable();
+        let Some(first) = fields.peek() else {
+            return Ok(None);
+        };
+        let name = match first.text.parse::<i32>() {
+            Ok(_) => None,
+            Err(_) => fields.next().map(|name| name.text.to_string()),
+        };
+        let levels = fields
+            .map(|field| field.parse::<i32>("a level"))
+            .collect::<ParseResult<Vec<i32>>>()?;
+        if name.is_some() && levels.is_empty() {
+            return Err(line.end().error("a level"));
+        }
+
+        let mut whole_report = StreamState::default();
+        let state = match &name {
+            Some(name) => self.streams.entry(name.clone()).or_default(),
+            None => &mut whole_report,
+        };
+        for level in levels {
+            // a stream's previous level may have come on an earlier line
+            let from = state.previous;
+            if let Some(violation) = state.push(level, &self.policy) {
+                return Ok(Some(Alert {
+                    line: line.line,
+                    stream: name,
+                    violation,
+                    from: from.expect("a violation needs an earlier level"),
+                    to: level,
+                }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::numbered_lines;
+
+    fn alerts(input: &str) -> Vec<String> {
+        let mut monitor = Monitor::new(SafetyPolicy::default());
+        numbered_lines(input)
+            .filter_map(|line| monitor.feed(line).unwrap())
+            .map(|alert| alert.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_alerts_once_per_stream() {
+        let input = "a 7 6\nb 1\na 4\nb 2 3\na 5\na 1\n1 2 7 8 9\nb 4\n";
+
+        assert_eq!(
+            alerts(input),
+            vec![
+                "line 5: stream \"a\" is unsafe, direction change at indices 2-3 (4 -> 5)",
+                "line 7: report is unsafe, step too large at indices 1-2 (2 -> 7)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_named_stream_needs_levels() {
+        let mut monitor = Monitor::new(SafetyPolicy::default());
+        let line = numbered_lines("a 1\nb\n").nth(1).unwrap();
+
+        assert_eq!(
+            monitor.feed(line).unwrap_err().to_string(),
+            "2:2: expected a level, found end of line"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! The rules a report has to follow to count as safe.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+/// Which way a report's levels have to move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Trend {
+    /// Either way, as long as it never changes.
+    Any,
+    Increasing,
+    Decreasing,
+}
+
+impl FromStr for Trend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "any" => Ok(Trend::Any),
+            "increasing" => Ok(Trend::Increasing),
+            "decreasing" => Ok(Trend::Decreasing),
+            _ => Err(format!(
+                "unknown trend {:?}: expected any, increasing or decreasing",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Trend::Any => write!(f, "any"),
+            Trend::Increasing => write!(f, "increasing"),
+            Trend::Decreasing => write!(f, "decreasing"),
+        }
+    }
+}
+
+/// What makes a report safe. The default is the puzzle's: every step is 1 to 3 levels in the
+/// same direction, and the Problem Dampener may drop one level.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SafetyPolicy {
+    /// Smallest change between neighbouring levels, not counting plateaus.
+    pub min_step: i32,
+    /// Largest change between neighbouring levels.
+    pub max_step: i32,
+    /// Whether neighbouring levels may be equal. Plateaus don't set the direction.
+    pub allow_plateaus: bool,
+    pub trend: Trend,
+    /// How many levels the Problem Dampener may drop.
+    pub max_removals: usize,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            allow_plateaus: false,
+            trend: Trend::Any,
+            max_removals: 1,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_step < 1 {
+            return Err(format!(
+                "min_step must be at least 1, not {}; use allow_plateaus for equal levels",
+                self.min_step
+            ));
+        }
+        if self.max_step < self.min_step {
+            return Err(format!(
+                "max_step {} is smaller than min_step {}",
+                self.max_step, self.min_step
+            ));
+        }
+        Ok(())
+    }
+
+    /// The signs a report's steps may have: 1 for increasing, -1 for decreasing.
+    pub fn signs(&self) -> &'static [i32] {
+        match self.trend {
+            Trend::Any => &[1, -1],
+            Trend::Increasing => &[1],
+            Trend::Decreasing => &[-1],
+        }
+    }
+
+    /// Whether `step` is allowed in a report heading in the direction of `sign`. Steps are
+    /// `i64` so the difference of any two `i32` levels fits.
+    pub fn allows_step(&self, step: i64, sign: i32) -> bool {
+        if step == 0 {
+            self.allow_plateaus
+        } else {
+            (self.min_step as i64..=self.max_step as i64).contains(&(step * sign as i64))
+        }
+    }
+
+    /// The index of the first level whose step from the previous one isn't allowed heading in
+    /// the direction of `sign`, leaving out the level at `skip`.
+    pub fn first_bad_step(&self, numbers: &[i32], sign: i32, skip: Option<usize>) -> Option<usize> {
+        let mut previous = None;
+        for (i, &level) in numbers.iter().enumerate() {
+            if Some(i) == skip {
+                continue;
+            }
+            if let Some(previous) = previous {
+                if !self.allows_step(level as i64 - previous as i64, sign) {
+                    return Some(i);
+                }
+            }
+            previous = Some(level);
+        }
+        None
+    }
+
+    /// Whether the report is safe as it stands.
+    pub fn is_safe(&self, numbers: &[i32]) -> bool {
+        self.signs()
+            .iter()
+            .any(|&sign| self.first_bad_step(numbers, sign, None).is_none())
+    }
+
+    /// Whether dropping at most `max_removals` levels makes the report safe.
+    pub fn is_safe_with_dampener(&self, numbers: &[i32]) -> bool {
+        match self.max_removals {
+            0 => self.is_safe(numbers),
+            1 => self.signs().iter().any(|&sign| {
+                match self.first_bad_step(numbers, sign, None) {
+                    None => true,
+                    // every other step survives whichever level is dropped, so the bad step
+                    // into level i can only be fixed by dropping level i - 1 or level i
+                    Some(i) => [i - 1, i]
+                        .into_iter()
+                        .any(|skip| self.first_bad_step(numbers, sign, Some(skip)).is_none()),
+                }
+            }),
+            max => crate::removals::minimum_removals(numbers, self).len() <= max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::explain::Rule;
+    use crate::monitor::StreamState;
+    use crate::removals::minimum_removals;
+
+    #[test]
+    fn test_custom_policy() {
+        let policy = SafetyPolicy {
+            min_step: 2,
+            max_step: 5,
+            allow_plateaus: true,
+            trend: Trend::Decreasing,
+            max_removals: 2,
+        };
+        policy.validate().unwrap();
+
+        assert!(policy.is_safe(&[20, 15, 15, 13]));
+        assert!(!policy.is_safe(&[13, 15, 20]));
+        assert!(!policy.is_safe(&[20, 19, 15]));
+        assert!(policy.is_safe_with_dampener(&[20, 19, 18, 15]));
+        assert!(!policy.is_safe_with_dampener(&[10, 11, 12, 14]));
+        assert_eq!(minimum_removals(&[10, 11, 12, 14], &policy).len(), 3);
+    }
+
+    #[test]
+    fn test_extreme_levels_do_not_overflow() {
+        let policy = SafetyPolicy::default();
+        let levels = [1, i32::MAX, i32::MIN];
+
+        assert!(!policy.is_safe(&levels));
+        assert!(!policy.is_safe_with_dampener(&levels));
+        assert_eq!(minimum_removals(&levels, &policy).len(), 2);
+
+        let mut state = StreamState::default();
+        let violations: Vec<Option<Rule>> = levels
+            .iter()
+            .map(|&level| state.push(level, &policy).map(|violation| violation.rule))
+            .collect();
+        assert_eq!(violations, vec![None, Some(Rule::StepTooLarge), None]);
+    }
+
+    #[test]
+    fn test_policy_from_toml_keeps_defaults() {
+        let policy: SafetyPolicy =
+            toml::from_str("trend = \"increasing\"\nmax_removals = 0\n").unwrap();
+
+        assert_eq!(
+            policy,
+            SafetyPolicy {
+                trend: Trend::Increasing,
+                max_removals: 0,
+                ..SafetyPolicy::default()
+            }
+        );
+        assert!(toml::from_str::<SafetyPolicy>("max_stp = 4").is_err());
+    }
+}
@@ -0,0 +1,151 @@
+//! The fewest levels to delete so a report becomes safe.
+//!
+//! The levels that stay form the longest subsequence whose steps the policy allows in one
+//! direction, so this finds that subsequence for each direction and drops everything else.
+
+use std::fmt;
+
+use crate::policy::SafetyPolicy;
+
+/// The indices of the fewest levels to drop to make the report safe under `policy`, in
+/// ascending order. Ignores the policy's `max_removals`.
+pub fn minimum_removals(numbers: &[i32], policy: &SafetyPolicy) -> Vec<usize> {
+    let kept = policy
+        .signs()
+        .iter()
+        .map(|&sign| longest_run(numbers, policy, sign))
+        .max_by_key(|kept| kept.len())
+        .unwrap_or_default();
+
+    let mut kept = kept.into_iter().peekable();
+    (0..numbers.len())
+        .filter(|&i| kept.next_if_eq(&i).is_none())
+        .collect()
+}
+
+/// The indices of the longest subsequence whose steps `policy` allows heading in the
+/// direction of `sign`.
+fn longest_run(numbers: &[i32], policy: &SafetyPolicy, sign: i32) -> Vec<usize> {
+    // length of the longest such subsequence ending at each level, and the level before it
+    let mut length = vec![1; numbers.len()];
+    let mut previous = vec![None; numbers.len()];
+    for i in 0..numbers.len() {
+        for j in 0..i {
+            if policy.allows_step(numbers[i] as i64 - numbers[j] as i64, sign)
+                && length[j] + 1 > length[i]
+            {
+                length[i] = length[j] + 1;
+                previous[i] = Some(j);
+            }
+        }
+    }
+
+    let Some(end) = (0..numbers.len()).max_by_key(|&i| (length[i], std::cmp::Reverse(i))) else {
+        return Vec::new();
+    };
+    let mut kept: Vec<usize> = std::iter::successors(Some(end), |&i| previous[i]).collect();
+    kept.reverse();
+    kept
+}
+
+/// How many reports need no removals, one, two, or more.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RemovalSummary {
+    pub none: usize,
+    pub one: usize,
+    pub two: usize,
+    pub more: usize,
+}
+
+impl RemovalSummary {
+    pub fn new(reports: &[Vec<i32>], policy: &SafetyPolicy) -> Self {
+        let mut summary = Self::default();
+        for numbers in reports {
+            match minimum_removals(numbers, policy).len() {
+                0 => summary.none += 1,
+                1 => summary.one += 1,
+                2 => summary.two += 1,
+                _ => summary.more += 1,
+            }
+        }
+        summary
+    }
+}
+
+impl fmt::Display for RemovalSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "no removals: {}", self.none)?;
+        writeln!(f, "1 removal: {}", self.one)?;
+        writeln!(f, "2 removals: {}", self.two)?;
+        writeln!(f, "3 or more removals: {}", self.more)
+    }
+}
+
+/// The report with the levels at `dropped` left out.
+pub fn without(numbers: &[i32], dropped: &[usize]) -> Vec<i32> {
+    numbers
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !dropped.contains(i))
+        .map(|(_, &level)| level)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{is_safe_by_removing_one_element, is_safe_report};
+    use aoc_core::generate::{seeded, Rng};
+
+    fn minimum_removals_default(numbers: &[i32]) -> Vec<usize> {
+        minimum_removals(numbers, &SafetyPolicy::default())
+    }
+
+    fn fixes(numbers: &[i32], dropped: &[usize]) -> bool {
+        is_safe_report(&without(numbers, dropped))
+    }
+
+    /// The fewest removals found by trying every subset of levels, smallest first.
+    fn brute_force(numbers: &[i32]) -> usize {
+        let n = numbers.len();
+        (0u32..1 << n)
+            .filter(|mask| {
+                let dropped: Vec<usize> = (0..n).filter(|i| mask & (1 << i) != 0).collect();
+                fixes(numbers, &dropped)
+            })
+            .map(|mask| mask.count_ones() as usize)
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_minimum_removals_on_example() {
+        assert_eq!(
+            minimum_removals_default(&[7, 6, 4, 2, 1]),
+            Vec::<usize>::new()
+        );
+        assert_eq!(minimum_removals_default(&[1, 3, 2, 4, 5]), vec![2]);
+        assert_eq!(minimum_removals_default(&[9, 7, 6, 2, 1]).len(), 2);
+        assert_eq!(minimum_removals_default(&[1, 2, 7, 8, 9]).len(), 2);
+    }
+
+    #[test]
+    fn test_minimum_removals_match_brute_force() {
+        let mut rng = seeded(16);
+        for _ in 0..2000 {
+            let numbers: Vec<i32> = (0..rng.random_range(1..=10))
+                .map(|_| rng.random_range(0..12))
+                .collect();
+            let dropped = minimum_removals(&numbers, &SafetyPolicy::default());
+
+            assert!(fixes(&numbers, &dropped), "{:?} {:?}", numbers, dropped);
+            assert_eq!(dropped.len(), brute_force(&numbers), "{:?}", numbers);
+            assert_eq!(
+                dropped.len() <= 1,
+                is_safe_by_removing_one_element(&numbers),
+                "{:?}",
+                numbers
+            );
+        }
+    }
+}
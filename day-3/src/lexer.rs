@@ -0,0 +1,300 @@
+//! A byte-at-a-time lexer for instructions hidden in corrupted memory.
+//!
+//! An instruction is a name, `(`, then as many 1-3 digit operands as its arity separated by
+//! `,`, then `)`: `mul(2,4)`, `do()`. Anything else is garbage and skipped. When a partial
+//! match goes wrong the lexer restarts one byte after where it began, so it finds exactly the
+//! instructions a left-to-right search would, and it only ever holds the bytes of the one
+//! instruction it is in the middle of.
+
+/// The name and operand count of an instruction the lexer looks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub name: String,
+    pub arity: usize,
+}
+
+impl Signature {
+    pub fn new(name: &str, arity: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            arity,
+        }
+    }
+}
+
+/// Where [`puzzle_signatures`] puts each of the puzzle's instructions.
+pub const MUL: usize = 0;
+pub const DO: usize = 1;
+pub const DONT: usize = 2;
+
+/// The instructions the puzzle uses.
+pub fn puzzle_signatures() -> Vec<Signature> {
+    vec![
+        Signature::new("mul", 2),
+        Signature::new("do", 0),
+        Signature::new("don't", 0),
+    ]
+}
+
+/// The most digits an operand may have.
+const MAX_DIGITS: usize = 3;
+
+/// One recognised instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Index into the lexer's signatures.
+    pub instruction: usize,
+    pub args: Vec<i64>,
+    /// Byte offset of the first character of the name.
+    pub offset: usize,
+    /// Length in bytes, from the name to the closing `)`.
+    pub len: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum State {
+    /// Between instructions.
+    Start,
+    /// The first `len` bytes of `signatures[instruction].name` have matched. Names that share
+    /// a prefix share this state until they differ.
+    Name { instruction: usize, len: usize },
+    /// Inside the parentheses, reading operands.
+    Args {
+        instruction: usize,
+        args: Vec<i64>,
+        value: i64,
+        digits: usize,
+    },
+}
+
+enum Step {
+    /// The byte can't start an instruction.
+    Skip,
+    /// The byte extends the instruction in progress; the state has moved on.
+    Continue,
+    /// The byte finishes an instruction.
+    Done { instruction: usize, args: Vec<i64> },
+    /// The byte breaks the instruction in progress.
+    Fail,
+}
+
+pub struct Lexer {
+    signatures: Vec<Signature>,
+    state: State,
+    /// The bytes of the instruction in progress, and the offset of the first.
+    pending: Vec<u8>,
+    start: usize,
+    /// How many bytes have been pushed.
+    offset: usize,
+}
+
+impl Lexer {
+    pub fn new(signatures: Vec<Signature>) -> Self {
+        Self {
+            signatures,
+            state: State::Start,
+            pending: Vec::new(),
+            start: 0,
+            offset: 0,
+        }
+    }
+
+    pub fn signatures(&self) -> &[Signature] {
+        &self.signatures
+    }
+
+    /// Feeds the next byte, returning the instruction it completes, if any.
+    pub fn push(&mut self, byte: u8) -> Option<Token> {
+        let offset = self.offset;
+        self.offset += 1;
+        self.feed(byte, offset)
+    }
+
+    /// Every instruction in `text`, in order.
+    pub fn tokens<'a>(&'a mut self, text: &'a str) -> impl Iterator<Item = Token> + 'a {
+        text.bytes().filter_map(move |byte| self.push(byte))
+    }
+
+    fn feed(&mut self, byte: u8, offset: usize) -> Option<Token> {
+        match self.step(byte) {
+            Step::Skip => None,
+            Step::Continue => {
+                if self.pending.is_empty() {
+                    self.start = offset;
+                }
+                self.pending.push(byte);
+                None
+            }
+            Step::Done { instruction, args } => {
+                let token = Token {
+                    instruction,
+                    args,
+                    offset: self.start,
+                    len: self.pending.len() + 1,
+                };
+                self.pending.clear();
+                self.state = State::Start;
+                Some(token)
+            }
+            Step::Fail => {
+                // start again one byte after the failed instruction began; an instruction
+                // can only finish on a `)`, so only the last byte fed back can finish one
+                let retry: Vec<u8> = self.pending.drain(..).skip(1).chain([byte]).collect();
+                let retry_start = self.start + 1;
+                self.state = State::Start;
+                let mut token = None;
+                for (i, byte) in retry.into_iter().enumerate() {
+                    token = self.feed(byte, retry_start + i).or(token);
+                }
+                token
+            }
+        }
+    }
+
+    /// Moves the current state on by `byte`. The state is only left for [`Lexer::feed`] to
+    /// reset when the instruction finishes or fails.
+    fn step(&mut self, byte: u8) -> Step {
+        match &mut self.state {
+            State::Start => match self.extend_name(0, 0, byte) {
+                Some(instruction) => {
+                    self.state = State::Name {
+                        instruction,
+                        len: 1,
+                    };
+                    Step::Continue
+                }
+                None => Step::Skip,
+            },
+            &mut State::Name { instruction, len } => {
+                let next = if byte == b'(' {
+                    // another name may share this prefix and end here
+                    let name = &self.signatures[instruction].name.as_bytes()[..len];
+                    self.signatures
+                        .iter()
+                        .position(|signature| signature.name.as_bytes() == name)
+                        .map(|instruction| State::Args {
+                            instruction,
+                            args: Vec::new(),
+                            value: 0,
+                            digits: 0,
+                        })
+                } else {
+                    self.extend_name(instruction, len, byte)
+                        .map(|instruction| State::Name {
+                            instruction,
+                            len: len + 1,
+                        })
+                };
+                match next {
+                    Some(next) => {
+                        self.state = next;
+                        Step::Continue
+                    }
+                    None => Step::Fail,
+                }
+            }
+            State::Args {
+                instruction,
+                args,
+                value,
+                digits,
+            } => {
+                let arity = self.signatures[*instruction].arity;
+                match byte {
+                    b'0'..=b'9' if *digits < MAX_DIGITS && args.len() < arity => {
+                        *value = *value * 10 + (byte - b'0') as i64;
+                        *digits += 1;
+                        Step::Continue
+                    }
+                    b',' if *digits > 0 && args.len() + 1 < arity => {
+                        args.push(*value);
+                        *value = 0;
+                        *digits = 0;
+                        Step::Continue
+                    }
+                    b')' if arity == 0 => Step::Done {
+                        instruction: *instruction,
+                        args: Vec::new(),
+                    },
+                    b')' if *digits > 0 && args.len() + 1 == arity => {
+                        let mut args = std::mem::take(args);
+                        args.push(*value);
+                        Step::Done {
+                            instruction: *instruction,
+                            args,
+                        }
+                    }
+                    _ => Step::Fail,
+                }
+            }
+        }
+    }
+
+    /// An instruction whose name starts with the first `len` bytes of `instruction`'s name
+    /// followed by `byte`.
+    fn extend_name(&self, instruction: usize, len: usize, byte: u8) -> Option<usize> {
+        let prefix = &self.signatures[instruction].name.as_bytes()[..len];
+        self.signatures.iter().position(|signature| {
+            let name = signature.name.as_bytes();
+            name.starts_with(prefix) && name.get(len) == Some(&byte)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(signatures: Vec<Signature>, text: &str) -> Vec<String> {
+        let mut lexer = Lexer::new(signatures);
+        let tokens: Vec<Token> = lexer.tokens(text).collect();
+        tokens
+            .iter()
+            .map(|token| {
+                let args: Vec<String> = token.args.iter().map(i64::to_string).collect();
+                format!(
+                    "{}@{}:{}({})",
+                    lexer.signatures()[token.instruction].name,
+                    token.offset,
+                    token.len,
+                    args.join(",")
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tokens_in_example() {
+        let text = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        assert_eq!(
+            names(puzzle_signatures(), text),
+            vec![
+                "mul@1:8(2,4)",
+                "don't@20:7()",
+                "mul@28:8(5,5)",
+                "mul@48:9(11,8)",
+                "do@59:4()",
+                "mul@64:8(8,5)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_operands_have_one_to_three_digits() {
+        assert_eq!(
+            names(puzzle_signatures(), "mul(1234,5)mul(123,4)mul(,4)mul(4,)"),
+            vec!["mul@11:10(123,4)"]
+        );
+    }
+
+    #[test]
+    fn test_restarts_inside_a_failed_match() {
+        let signatures = vec![Signature::new("add", 2), Signature::new("do", 0)];
+
+        assert_eq!(
+            names(signatures, "adon't()ado()mul(1,add(2,3))"),
+            vec!["do@9:4()", "add@19:8(2,3)"]
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{Answer, Generate, ParseResult, Solution};
+
+use stream::Scanner;
+
+pub mod lexer;
+pub mod nested;
+pub mod stream;
+pub mod trace;
+pub mod vm;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    /// Sums every `mul(a,b)` in the corrupted memory.
+    fn part1(file_data: &Self::Input) -> Answer {
+        scan(file_data).all.into()
+    }
+
+    /// Sums only the `mul(a,b)` instructions that aren't switched off by a `don't()`.
+    fn part2(file_data: &Self::Input) -> Answer {
+        scan(file_data).enabled.into()
+    }
+}
+
+/// Fragments that look a bit like instructions but are not.
+const NOISE: &[&str] = &[
+    "x",
+    "%&",
+    "!@^",
+    "+",
+    "mul[3,7]",
+    "mul(32,64]",
+    "mul ( 2 , 4 )",
+    "mul(4*",
+    "do_not_",
+    "then(",
+    "why()",
+    "from()",
+    "select()",
+    "don't",
+    "do(",
+    "undo",
+    "?(12,34)",
+    "mul(6,9!",
+];
+
+impl Generate for Day3 {
+    /// Corrupted memory: valid `mul`s, `do()`s and `don't()`s mixed in with noise.
+    fn generate(rng: &mut StdRng) -> String {
+        let mut memory = String::new();
+        for _ in 0..rng.random_range(1..80) {
+            match rng.random_range(0..10) {
+                0..=2 => {
+                    let a = rng.random_range(1..1000);
+                    let b = rng.random_range(1..1000);
+                    memory += &format!("mul({},{})", a, b);
+                }
+                3 => memory += "do()",
+                4 => memory += "don't()",
+                _ => memory += NOISE[rng.random_range(0..NOISE.len())],
+            }
+        }
+        memory + "\n"
+    }
+}
+
+/// The sums of the `mul`s in some corrupted memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    /// Every `mul`.
+    pub all: i64,
+    /// The `mul`s made while enabled: memory starts enabled, `don't()` disables it and `do()`
+    /// enables it again.
+    pub enabled: i64,
+}
+
+/// Both sums in one pass over the memory.
+pub fn scan(memory: &str) -> Totals {
+    let mut scanner = Scanner::new();
+    scanner.feed(memory.as_bytes());
+    scanner.totals()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::check_against_reference;
+
+    /// Reads a 1-3 digit number at the start of `text`.
+    fn number(text: &str) -> Option<(i32, &str)> {
+        let digits = text.len() - text.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if !(1..=3).contains(&digits) {
+            return None;
+        }
+        Some((text[..digits].parse().unwrap(), &text[digits..]))
+    }
+
+    /// Tries `mul(a,b)` at every position, tracking `do()` and `don't()` as it goes.
+    fn reference(input: &str) -> (Answer, Answer) {
+        let (mut all, mut enabled_only, mut enabled) = (0, 0, true);
+        for start in 0..input.len() {
+            let rest = &input[start..];
+            if rest.starts_with("do()") {
+                enabled = true;
+            } else if rest.starts_with("don't()") {
+                enabled = false;
+            }
+            let Some(rest) = rest.strip_prefix("mul(") else {
+                continue;
+            };
+            let Some((a, rest)) = number(rest) else {
+                continue;
+            };
+            let Some((b, rest)) = rest.strip_prefix(',').and_then(number) else {
+                continue;
+            };
+            if rest.starts_with(')') {
+                all += a * b;
+                if enabled {
+                    enabled_only += a * b;
+                }
+            }
+        }
+        (all.into(), enabled_only.into())
+    }
+
+    #[test]
+    fn test_scan_example() {
+        assert_eq!(
+            scan(include_str!("example2.txt")),
+            Totals {
+                all: 161,
+                enabled: 48
+            }
+        );
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day3>(100, reference);
+    }
+}
@@ -0,0 +1,259 @@
+//! An opt-in grammar where operands may themselves be calls, as in `mul(mul(2,3),4)`.
+//!
+//! An operand is a 1-3 digit number or a call to a value instruction; toggles like `do()`
+//! only count at the top level. As in the strict lexer, the memory is searched left to right
+//! and anything that doesn't parse is skipped one byte at a time, so `mul(mul(2,3)!` still
+//! finds the inner `mul(2,3)`.
+
+use std::fmt;
+
+use crate::vm::{InstructionSet, Overflow, Semantics};
+
+/// How deeply calls may nest before the parse gives up on them.
+const MAX_DEPTH: usize = 64;
+
+/// The most digits a number may have.
+const MAX_DIGITS: usize = 3;
+
+/// A parsed and evaluated expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Number(i64),
+    Call {
+        name: String,
+        args: Vec<Expr>,
+        /// What the call evaluated to; toggles have no value.
+        value: Option<i64>,
+    },
+}
+
+impl Expr {
+    pub fn value(&self) -> Option<i64> {
+        match self {
+            Expr::Number(number) => Some(*number),
+            Expr::Call { value, .. } => *value,
+        }
+    }
+
+    fn write_tree(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+        match self {
+            Expr::Number(number) => writeln!(f, "{}{}", indent, number),
+            Expr::Call { name, args, value } => {
+                match value {
+                    Some(value) => writeln!(f, "{}{} = {}", indent, name, value)?,
+                    None => writeln!(f, "{}{}", indent, name)?,
+                }
+                args.iter().try_for_each(|arg| arg.write_tree(f, depth + 1))
+            }
+        }
+    }
+}
+
+/// One expression found at the top level of the memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopLevel {
+    /// Byte offset and length of the whole expression.
+    pub offset: usize,
+    pub len: usize,
+    /// Whether the machine was enabled when the expression ran.
+    pub enabled: bool,
+    pub expr: Expr,
+}
+
+impl TopLevel {
+    /// What the expression added to the accumulator.
+    pub fn contribution(&self) -> i64 {
+        match self.expr.value() {
+            Some(value) if self.enabled => value,
+            _ => 0,
+        }
+    }
+}
+
+/// Prints the expression as an indented tree, one call or number per line.
+impl fmt::Display for TopLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "byte {}: {}",
+            self.offset,
+            if self.enabled { "enabled" } else { "disabled" }
+        )?;
+        self.expr.write_tree(f, 1)
+    }
+}
+
+struct Parser<'a> {
+    memory: &'a [u8],
+    set: &'a InstructionSet,
+}
+
+impl Parser<'_> {
+    /// The operand starting at `start` and where it ends, or `None` if there isn't one.
+    fn operand(&self, start: usize, depth: usize) -> Result<Option<(Expr, usize)>, Overflow> {
+        let digits = self.memory[start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        match digits {
+            0 => match self.call(start, depth)? {
+                // toggles have no value to pass on
+                Some((expr, end)) if expr.value().is_some() => Ok(Some((expr, end))),
+                _ => Ok(None),
+            },
+            1..=MAX_DIGITS => {
+                let number = self.memory[start..start + digits]
+                    .iter()
+                    .fold(0, |number, &digit| number * 10 + (digit - b'0') as i64);
+                Ok(Some((Expr::Number(number), start + digits)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// The instruction whose name and `(` start at `start`.
+    fn instruction_at(&self, start: usize) -> Option<usize> {
+        let rest = &self.memory[start..];
+        self.set.signatures().iter().position(|signature| {
+            let name = signature.name.as_bytes();
+            rest.starts_with(name) && rest.get(name.len()) == Some(&b'(')
+        })
+    }
+
+    /// The call starting at `start` and where it ends, or `None` if there isn't one.
+    fn call(&self, start: usize, depth: usize) -> Result<Option<(Expr, usize)>, Overflow> {
+        if depth > MAX_DEPTH {
+            return Ok(None);
+        }
+        let Some(instruction) = self.instruction_at(start) else {
+            return Ok(None);
+        };
+        let signature = &self.set.signatures()[instruction];
+
+        let mut end = start + signature.name.len() + 1;
+        let mut args = Vec::with_capacity(signature.arity);
+        for i in 0..signature.arity {
+            if i > 0 {
+                if self.memory.get(end) != Some(&b',') {
+                    return Ok(None);
+                }
+                end += 1;
+            }
+            let Some((arg, after)) = self.operand(end, depth + 1)? else {
+                return Ok(None);
+            };
+            args.push(arg);
+            end = after;
+        }
+        if self.memory.get(end) != Some(&b')') {
+            return Ok(None);
+        }
+
+        let value = match self.set.semantics(instruction) {
+            Semantics::Value(compute) => {
+                let operands: Vec<i64> = args.iter().filter_map(Expr::value).collect();
+                let value = compute(&operands).ok_or_else(|| Overflow {
+                    instruction: signature.name.clone(),
+                    offset: start,
+                })?;
+                Some(value)
+            }
+            Semantics::Enable(_) => None,
+        };
+        let expr = Expr::Call {
+            name: signature.name.clone(),
+            args,
+            value,
+        };
+        Ok(Some((expr, end + 1)))
+    }
+}
+
+/// Every top-level expression in `memory`, evaluated with `set` and tracking its toggles.
+pub fn evaluate(memory: &str, set: &InstructionSet) -> Result<Vec<TopLevel>, Overflow> {
+    let parser = Parser {
+        memory: memory.as_bytes(),
+        set,
+    };
+    let mut enabled = true;
+    // only kept so a total too big for an i64 is an error, as it is in strict mode
+    let mut accumulator: i64 = 0;
+    let mut found = Vec::new();
+    let mut start = 0;
+    while start < memory.len() {
+        let Some((expr, end)) = parser.call(start, 0)? else {
+            start += 1;
+            continue;
+        };
+        let top = TopLevel {
+            offset: start,
+            len: end - start,
+            enabled,
+            expr,
+        };
+        let instruction = parser
+            .instruction_at(start)
+            .expect("a call was just parsed here");
+        if let Semantics::Enable(on) = set.semantics(instruction) {
+            enabled = *on;
+        }
+        accumulator = accumulator
+            .checked_add(top.contribution())
+            .ok_or_else(|| Overflow {
+                instruction: set.signatures()[instruction].name.clone(),
+                offset: start,
+            })?;
+        found.push(top);
+        start = end;
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::Machine;
+
+    fn total(found: &[TopLevel]) -> i64 {
+        found.iter().map(TopLevel::contribution).sum()
+    }
+
+    #[test]
+    fn test_nested_calls() {
+        let memory = "mul(mul(2,3),4)x add(1,sub(9,mul(2,2)))don't()mul(mul(1,1),7)!mul(mul(2,3)!";
+        let found = evaluate(memory, &InstructionSet::extended()).unwrap();
+        let trees: Vec<String> = found.iter().map(TopLevel::to_string).collect();
+
+        assert_eq!(
+            trees,
+            vec![
+                "byte 0: enabled\n  mul = 24\n    mul = 6\n      2\n      3\n    4\n",
+                "byte 17: enabled\n  add = 6\n    1\n    sub = 5\n      9\n      mul = 4\n        2\n        2\n",
+                "byte 39: enabled\n  don't\n",
+                "byte 46: disabled\n  mul = 7\n    mul = 1\n      1\n      1\n    7\n",
+                "byte 66: disabled\n  mul = 6\n    2\n    3\n",
+            ]
+        );
+        assert_eq!(total(&found), 30);
+    }
+
+    #[test]
+    fn test_agrees_with_strict_mode_without_nesting() {
+        let memory = include_str!("data.txt");
+        let set = InstructionSet::puzzle();
+
+        assert_eq!(
+            total(&evaluate(memory, &set).unwrap()),
+            Machine::new(&set).run(memory).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_toggles_are_not_operands() {
+        let found = evaluate("mul(do(),2)mul(4,5)", &InstructionSet::puzzle()).unwrap();
+        let offsets: Vec<usize> = found.iter().map(|top| top.offset).collect();
+
+        assert_eq!(offsets, vec![4, 11]);
+    }
+}
@@ -0,0 +1,116 @@
+//! Both sums for memory dumps too big to load at once.
+//!
+//! The lexer takes one byte at a time and keeps any instruction it is partway through, so the
+//! memory can arrive in chunks split anywhere, even in the middle of `mul(12,3)`. Only the
+//! current chunk and the instruction in progress are ever held.
+
+use std::io::{self, BufRead};
+
+use crate::lexer::{puzzle_signatures, Lexer, DO, DONT, MUL};
+use crate::Totals;
+
+/// The sums so far, and whether `mul`s are currently enabled.
+pub struct Scanner {
+    lexer: Lexer,
+    enabled: bool,
+    totals: Totals,
+}
+
+impl Default for Scanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scanner {
+    pub fn new() -> Self {
+        Self {
+            lexer: Lexer::new(puzzle_signatures()),
+            enabled: true,
+            totals: Totals::default(),
+        }
+    }
+
+    /// Scans the next piece of memory.
+    pub fn feed(&mut self, chunk: &[u8]) {
+        for &byte in chunk {
+            let Some(token) = self.lexer.push(byte) else {
+                continue;
+            };
+            match token.instruction {
+                MUL => {
+                    let product = token.args[0] * token.args[1];
+                    self.totals.all += product;
+                    if self.enabled {
+                        self.totals.enabled += product;
+                    }
+                }
+                DO => self.enabled = true,
+                DONT => self.enabled = false,
+                _ => unreachable!("not a puzzle instruction"),
+            }
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// The sums of every instruction finished so far.
+    pub fn totals(&self) -> Totals {
+        self.totals
+    }
+}
+
+/// Both sums for everything `reader` holds, scanned at most `chunk_size` bytes at a time.
+pub fn stream_totals(mut reader: impl BufRead, chunk_size: usize) -> io::Result<Totals> {
+    let mut scanner = Scanner::new();
+    loop {
+        let chunk = match reader.fill_buf() {
+            Ok([]) => break,
+            Ok(buffer) => &buffer[..buffer.len().min(chunk_size)],
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        scanner.feed(chunk);
+        let len = chunk.len();
+        reader.consume(len);
+    }
+    Ok(scanner.totals())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scan;
+
+    const MEMORY: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))mul(123,456)";
+
+    #[test]
+    fn test_split_at_every_boundary() {
+        let expected = scan(MEMORY);
+        for split in 0..=MEMORY.len() {
+            let (first, second) = MEMORY.as_bytes().split_at(split);
+            let mut scanner = Scanner::new();
+            scanner.feed(first);
+            scanner.feed(second);
+
+            assert_eq!(scanner.totals(), expected, "split at {}", split);
+        }
+    }
+
+    #[test]
+    fn test_every_chunk_size() {
+        let memory = include_str!("data.txt");
+        let expected = scan(memory);
+        for chunk_size in (1..=64).chain([1000, memory.len()]) {
+            assert_eq!(
+                stream_totals(memory.as_bytes(), chunk_size).unwrap(),
+                expected,
+                "chunk size {}",
+                chunk_size
+            );
+        }
+    }
+}
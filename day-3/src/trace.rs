@@ -0,0 +1,152 @@
+//! Every instruction the interpreter ran, where it was and what it added, for debugging the
+//! enable/disable logic.
+
+use std::fmt;
+
+use crate::vm::{InstructionSet, Machine, Overflow};
+
+/// One instruction as it ran.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// Byte offset of the instruction in the memory.
+    pub offset: usize,
+    /// Length in bytes.
+    pub len: usize,
+    /// 1-based line and column, the column counted in characters.
+    pub line: usize,
+    pub column: usize,
+    pub name: String,
+    pub args: Vec<i64>,
+    /// Whether the machine was enabled when the instruction ran.
+    pub enabled: bool,
+    /// The value the instruction computed; toggles have none.
+    pub value: Option<i64>,
+}
+
+impl TraceEntry {
+    /// What the instruction added to the accumulator.
+    pub fn contribution(&self) -> i64 {
+        match self.value {
+            Some(value) if self.enabled => value,
+            _ => 0,
+        }
+    }
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<String> = self.args.iter().map(i64::to_string).collect();
+        write!(
+            f,
+            "byte {} (line {}, column {}): {}({}) {}",
+            self.offset,
+            self.line,
+            self.column,
+            self.name,
+            args.join(","),
+            if self.enabled { "enabled" } else { "disabled" }
+        )?;
+        match self.value {
+            Some(value) if self.enabled => write!(f, ", adds {}", value),
+            Some(value) => write!(f, ", skips {}", value),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Runs `memory` on `set`, recording every instruction.
+pub fn trace(memory: &str, set: &InstructionSet) -> Result<Vec<TraceEntry>, Overflow> {
+    let mut machine = Machine::new(set);
+    // where the last entry was, so finding each line and column only looks at new bytes
+    let (mut seen, mut line, mut column) = (0, 1, 1);
+    let mut entries = Vec::new();
+    for token in set.lexer().tokens(memory) {
+        for c in memory[seen..token.offset].chars() {
+            if c == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        seen = token.offset;
+
+        let executed = machine.execute(&token)?;
+        entries.push(TraceEntry {
+            offset: token.offset,
+            len: token.len,
+            line,
+            column,
+            name: set.signatures()[token.instruction].name.clone(),
+            args: token.args,
+            enabled: executed.enabled,
+            value: executed.value,
+        });
+    }
+    Ok(entries)
+}
+
+const GREEN: &str = "\x1b[1;32m";
+const CYAN: &str = "\x1b[1;36m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// The memory with ANSI colours: instructions that count in bold green, toggles in bold cyan
+/// and instructions skipped while disabled dimmed. Garbage is left as it is.
+pub fn highlight(memory: &str, entries: &[TraceEntry]) -> String {
+    let mut out = String::with_capacity(memory.len() * 2);
+    let mut seen = 0;
+    for entry in entries {
+        out += &memory[seen..entry.offset];
+        let colour = match entry.value {
+            None => CYAN,
+            Some(_) if entry.enabled => GREEN,
+            Some(_) => DIM,
+        };
+        out += colour;
+        out += &memory[entry.offset..entry.offset + entry.len];
+        out += RESET;
+        seen = entry.offset + entry.len;
+    }
+    out += &memory[seen..];
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_example() {
+        let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)\n+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let entries = trace(memory, &InstructionSet::puzzle()).unwrap();
+        let lines: Vec<String> = entries.iter().map(TraceEntry::to_string).collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "byte 1 (line 1, column 2): mul(2,4) enabled, adds 8",
+                "byte 20 (line 1, column 21): don't() enabled",
+                "byte 28 (line 1, column 29): mul(5,5) disabled, skips 25",
+                "byte 49 (line 2, column 13): mul(11,8) disabled, skips 88",
+                "byte 60 (line 2, column 24): do() disabled",
+                "byte 65 (line 2, column 29): mul(8,5) enabled, adds 40",
+            ]
+        );
+        assert_eq!(
+            entries.iter().map(TraceEntry::contribution).sum::<i64>(),
+            48
+        );
+    }
+
+    #[test]
+    fn test_highlight_wraps_each_instruction() {
+        let memory = "!mul(1,2)don't()mul(3,4)?";
+        let entries = trace(memory, &InstructionSet::puzzle()).unwrap();
+
+        assert_eq!(
+            highlight(memory, &entries),
+            "!\x1b[1;32mmul(1,2)\x1b[0m\x1b[1;36mdon't()\x1b[0m\x1b[2mmul(3,4)\x1b[0m?"
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! A small interpreter for instructions found in corrupted memory.
+//!
+//! Each instruction is registered with a name, an arity and what it does. Value instructions
+//! like `mul(2,4)` add what they compute to a running accumulator while the machine is
+//! enabled; toggles like `do()` and `don't()` switch it on and off. All arithmetic is checked,
+//! so a log that would overflow an `i64` is an error rather than a wrong answer.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::lexer::{Lexer, Signature, Token};
+
+/// Computes a value instruction's result from its operands, or `None` if that overflows.
+pub type Compute = Box<dyn Fn(&[i64]) -> Option<i64>>;
+
+/// What an instruction does when it runs.
+pub enum Semantics {
+    /// Computes a value that is added to the accumulator while the machine is enabled.
+    Value(Compute),
+    /// Turns the machine on or off.
+    Enable(bool),
+}
+
+/// The instructions a [`Machine`] understands.
+#[derive(Default)]
+pub struct InstructionSet {
+    signatures: Vec<Signature>,
+    semantics: Vec<Semantics>,
+}
+
+impl InstructionSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The puzzle's `mul`, `do` and `don't`, at the indices the lexer's constants give.
+    pub fn puzzle() -> Self {
+        let mut set = Self::new();
+        set.register("mul", 2, Semantics::Value(Box::new(product)));
+        set.register("do", 0, Semantics::Enable(true));
+        set.register("don't", 0, Semantics::Enable(false));
+        set
+    }
+
+    /// The puzzle's instructions plus `add` and `sub`.
+    pub fn extended() -> Self {
+        let mut set = Self::puzzle();
+        set.register("add", 2, Semantics::Value(Box::new(sum)));
+        set.register(
+            "sub",
+            2,
+            Semantics::Value(Box::new(|args: &[i64]| {
+                let (first, rest) = args.split_first()?;
+                rest.iter().try_fold(*first, |a, &b| a.checked_sub(b))
+            })),
+        );
+        set
+    }
+
+    /// Adds an instruction, replacing any earlier one with the same name.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is empty or has anything other than letters, digits, `_` and `'` in it; the
+    /// lexer relies on names never holding brackets, commas or digits at the start.
+    pub fn register(&mut self, name: &str, arity: usize, semantics: Semantics) -> &mut Self {
+        assert!(
+            name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '\''),
+            "invalid instruction name {:?}",
+            name
+        );
+        let signature = Signature::new(name, arity);
+        match self.signatures.iter().position(|old| old.name == name) {
+            Some(index) => {
+                self.signatures[index] = signature;
+                self.semantics[index] = semantics;
+            }
+            None => {
+                self.signatures.push(signature);
+                self.semantics.push(semantics);
+            }
+        }
+        self
+    }
+
+    pub fn signatures(&self) -> &[Signature] {
+        &self.signatures
+    }
+
+    /// What the instruction at `index` in [`InstructionSet::signatures`] does.
+    pub fn semantics(&self, index: usize) -> &Semantics {
+        &self.semantics[index]
+    }
+
+    /// A lexer that recognises these instructions; its tokens index into this set.
+    pub fn lexer(&self) -> Lexer {
+        Lexer::new(self.signatures.clone())
+    }
+}
+
+fn product(args: &[i64]) -> Option<i64> {
+    args.iter().try_fold(1i64, |a, &b| a.checked_mul(b))
+}
+
+fn sum(args: &[i64]) -> Option<i64> {
+    args.iter().try_fold(0i64, |a, &b| a.checked_add(b))
+}
+
+/// An instruction overflowed, either computing its value or adding it to the accumulator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub instruction: String,
+    /// Byte offset of the instruction in the memory.
+    pub offset: usize,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at byte {} overflows a 64-bit accumulator",
+            self.instruction, self.offset
+        )
+    }
+}
+
+impl Error for Overflow {}
+
+/// What running one instruction did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Executed {
+    /// Whether the machine was enabled when the instruction ran.
+    pub enabled: bool,
+    /// The value the instruction computed; toggles have none. It only reaches the
+    /// accumulator if the machine was enabled.
+    pub value: Option<i64>,
+}
+
+pub struct Machine<'a> {
+    set: &'a InstructionSet,
+    accumulator: i64,
+    enabled: bool,
+}
+
+impl<'a> Machine<'a> {
+    /// A machine with an empty accumulator, starting enabled.
+    pub fn new(set: &'a InstructionSet) -> Self {
+        Self {
+            set,
+            accumulator: 0,
+            enabled: true,
+        }
+    }
+
+    pub fn accumulator(&self) -> i64 {
+        self.accumulator
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Runs one instruction lexed with this machine's instruction set.
+    pub fn execute(&mut self, token: &Token) -> Result<Executed, Overflow> {
+        let overflow = || Overflow {
+            instruction: self.set.signatures[token.instruction].name.clone(),
+            offset: token.offset,
+        };
+        let enabled = self.enabled;
+        let value = match &self.set.semantics[token.instruction] {
+            Semantics::Value(compute) => {
+                let value = compute(&token.args).ok_or_else(overflow)?;
+                if enabled {
+                    self.accumulator = self.accumulator.checked_add(value).ok_or_else(overflow)?;
+                }
+                Some(value)
+            }
+            Semantics::Enable(on) => {
+                self.enabled = *on;
+                None
+            }
+        };
+        Ok(Executed { enabled, value })
+    }
+
+    /// Runs every instruction in `memory`, returning the accumulator.
+    pub fn run(&mut self, memory: &str) -> Result<i64, Overflow> {
+        for token in self.set.lexer().tokens(memory) {
+            self.execute(&token)?;
+        }
+        Ok(self.accumulator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scan;
+
+    #[test]
+    fn test_puzzle_set_matches_scan() {
+        let memory = include_str!("data.txt");
+
+        assert_eq!(
+            Machine::new(&InstructionSet::puzzle()).run(memory),
+            Ok(scan(memory).enabled)
+        );
+    }
+
+    #[test]
+    fn test_extended_set() {
+        let set = InstructionSet::extended();
+
+        assert_eq!(
+            Machine::new(&set).run("add(2,3)sub(1,10)don't()add(1,1)do()mul(4,5)"),
+            Ok(5 - 9 + 20)
+        );
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let mut set = InstructionSet::puzzle();
+        set.register("pow", 7, Semantics::Value(Box::new(product)));
+
+        assert_eq!(
+            Machine::new(&set).run("mul(1,2) pow(999,999,999,999,999,999,999)"),
+            Err(Overflow {
+                instruction: "pow".to_string(),
+                offset: 9,
+            })
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Searching the word search for a whole list of words at once.
+//!
+//! The words go into an Aho–Corasick automaton, which finds every word in a line of letters
+//! in one pass, however many words there are. Each row, column and diagonal is read in both
+//! directions, so every word is found wherever and however it is written.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_core::{numbered_lines, Coord, Direction, Grid, ParseResult};
+
+/// A trie of the words with failure links, for matching all of them in one pass.
+pub struct Automaton {
+    /// The trie's edges out of each state; state 0 is the root.
+    next: Vec<HashMap<char, usize>>,
+    /// The state for the longest proper suffix of this state's text that is also in the trie.
+    fail: Vec<usize>,
+    /// The word this state spells out, if it is a whole word.
+    word: Vec<Option<usize>>,
+    /// The nearest state down the failure links that is a whole word, so every word ending at
+    /// a position is found without walking states that aren't.
+    output: Vec<Option<usize>>,
+    /// Each word's length in letters.
+    lengths: Vec<usize>,
+}
+
+impl Automaton {
+    /// Builds the automaton for `words`, which are told apart by their index. Empty words
+    /// never match, and a repeated word only matches as its first copy.
+    pub fn new<S: AsRef<str>>(words: &[S]) -> Self {
+        let mut automaton = Self {
+            next: vec![HashMap::new()],
+            fail: vec![0],
+            word: vec![None],
+            output: vec![None],
+            lengths: Vec::with_capacity(words.len()),
+        };
+        for (index, word) in words.iter().enumerate() {
+            let mut state = 0;
+            for c in word.as_ref().chars() {
+                state = match automaton.next[state].get(&c) {
+                    Some(&next) => next,
+                    None => {
+                        automaton.next.push(HashMap::new());
+                        automaton.fail.push(0);
+                        automaton.word.push(None);
+                        automaton.output.push(None);
+                        let next = automaton.next.len() - 1;
+                        automaton.next[state].insert(c, next);
+                        next
+                    }
+                };
+            }
+            if state != 0 {
+                automaton.word[state].get_or_insert(index);
+            }
+            automaton.lengths.push(word.as_ref().chars().count());
+        }
+
+        // breadth first, so every state's failure link points at a state already linked
+        let mut queue: VecDeque<usize> = automaton.next[0].values().copied().collect();
+        while let Some(state) = queue.pop_front() {
+            let edges: Vec<(char, usize)> = automaton.next[state]
+                .iter()
+                .map(|(&c, &s)| (c, s))
+                .collect();
+            for (c, child) in edges {
+                let mut fallback = automaton.fail[state];
+                while fallback != 0 && !automaton.next[fallback].contains_key(&c) {
+                    fallback = automaton.fail[fallback];
+                }
+                let fail = automaton.next[fallback].get(&c).copied().unwrap_or(0);
+                automaton.fail[child] = fail;
+                automaton.output[child] = if automaton.word[fail].is_some() {
+                    Some(fail)
+                } else {
+                    automaton.output[fail]
+                };
+                queue.push_back(child);
+            }
+        }
+        automaton
+    }
+
+    /// The number of letters in word `word`.
+    pub fn word_len(&self, word: usize) -> usize {
+        self.lengths[word]
+    }
+
+    /// Calls `found(end, word)` for every word in `text`, `end` being the index of its last
+    /// letter.
+    pub fn find(&self, text: impl IntoIterator<Item = char>, mut found: impl FnMut(usize, usize)) {
+        let mut state = 0;
+        for (end, c) in text.into_iter().enumerate() {
+            while state != 0 && !self.next[state].contains_key(&c) {
+                state = self.fail[state];
+            }
+            state = self.next[state].get(&c).copied().unwrap_or(0);
+
+            let mut matched = if self.word[state].is_some() {
+                Some(state)
+            } else {
+                self.output[state]
+            };
+            while let Some(at) = matched {
+                found(end, self.word[at].expect("output states are whole words"));
+                matched = self.output[at];
+            }
+        }
+    }
+}
+
+/// Reads a word list: one word per line, ignoring blank lines, surrounding space and repeats.
+pub fn parse_words(text: &str) -> ParseResult<Vec<String>> {
+    let mut words: Vec<String> = Vec::new();
+    let mut seen = HashSet::new();
+    for line in numbered_lines(text).filter(|line| !line.is_blank()) {
+        let mut fields = line.fields();
+        let word = fields.next().expect("the line isn't blank");
+        if let Some(extra) = fields.next() {
+            return Err(extra.error("end of line after the word"));
+        }
+        if seen.insert(word.text) {
+            words.push(word.text.to_string());
+        }
+    }
+    Ok(words)
+}
+
+/// Where one word was found: its first letter and the way it reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub start: Coord,
+    pub direction: Direction,
+}
+
+/// Every placement of every word.
+pub struct WordCounts {
+    pub words: Vec<String>,
+    /// The placements of each word, in the same order as `words`.
+    pub placements: Vec<Vec<Placement>>,
+}
+
+impl WordCounts {
+    /// Searches every line of the grid in all eight directions for all of `words`. A
+    /// one-letter word reads the same every way, so it is only counted once per cell.
+    pub fn new(word_search: &Grid<char>, words: Vec<String>) -> Self {
+        let automaton = Automaton::new(&words);
+        let mut placements = vec![Vec::new(); words.len()];
+        for direction in Direction::ALL {
+            for line in word_search.lines(direction) {
+                let letters = line.iter().map(|&coord| word_search[coord]);
+                automaton.find(letters, |end, word| {
+                    let len = automaton.word_len(word);
+                    if len > 1 || direction == Direction::Right {
+                        placements[word].push(Placement {
+                            start: line[end + 1 - len],
+                            direction,
+                        });
+                    }
+                });
+            }
+        }
+        for found in &mut placements {
+            found.sort_by_key(|placement| placement.start);
+        }
+        Self { words, placements }
+    }
+
+    pub fn count(&self, word: usize) -> usize {
+        self.placements[word].len()
+    }
+
+    pub fn total(&self) -> usize {
+        self.placements.iter().map(Vec::len).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_all_directions, Day4};
+    use aoc_core::generate::{seeded, Rng};
+    use aoc_core::Solution;
+
+    /// Every placement found by trying each word from each cell in each direction.
+    fn brute_force(word_search: &Grid<char>, word: &str) -> Vec<Placement> {
+        let letters: Vec<char> = word.chars().collect();
+        let directions: &[Direction] = if letters.len() == 1 {
+            &[Direction::Right]
+        } else {
+            &Direction::ALL
+        };
+        let mut found = Vec::new();
+        for start in word_search.coords() {
+            for &direction in directions {
+                let mut ray = word_search.ray(start, direction);
+                if letters
+                    .iter()
+                    .all(|&letter| ray.next().is_some_and(|coord| word_search[coord] == letter))
+                {
+                    found.push(Placement { start, direction });
+                }
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn test_xmas_matches_part1() {
+        let word_search = Day4::parse(include_str!("example.txt")).unwrap();
+        let counts = WordCounts::new(&word_search, vec!["XMAS".to_string()]);
+
+        assert_eq!(counts.count(0), 18);
+        assert_eq!(counts.count(0) as i32, check_all_directions(&word_search));
+    }
+
+    #[test]
+    fn test_overlapping_words_match_brute_force() {
+        let mut rng = seeded(25);
+        for _ in 0..200 {
+            let (height, width) = (rng.random_range(1..8), rng.random_range(1..8));
+            let rows: Vec<Vec<char>> = (0..height)
+                .map(|_| {
+                    (0..width)
+                        .map(|_| ['A', 'B', 'C'][rng.random_range(0..3)])
+                        .collect()
+                })
+                .collect();
+            let word_search = Grid::from_rows(rows).unwrap();
+            // short words over few letters, so they overlap and nest inside each other
+            let words: Vec<String> = (0..rng.random_range(1..6))
+                .map(|_| {
+                    (0..rng.random_range(1..4))
+                        .map(|_| ['A', 'B', 'C'][rng.random_range(0..3)])
+                        .collect()
+                })
+                .collect();
+            let words = parse_words(&words.join("\n")).unwrap();
+            let counts = WordCounts::new(&word_search, words.clone());
+
+            for (index, word) in words.iter().enumerate() {
+                let mut expected = brute_force(&word_search, word);
+                let mut found = counts.placements[index].clone();
+                found.sort_by_key(|placement| (placement.start, placement.direction.delta()));
+                expected.sort_by_key(|placement| (placement.start, placement.direction.delta()));
+
+                assert_eq!(found, expected, "{:?} in {:?}", word, word_search);
+            }
+        }
+    }
+
+    #[test]
+    fn test_word_list_rejects_spaces() {
+        assert_eq!(
+            parse_words("XMAS\n\nSAMX\nXMAS\n").unwrap(),
+            vec!["XMAS", "SAMX"]
+        );
+        assert_eq!(
+            parse_words("XMAS\nMAS X\n").unwrap_err().to_string(),
+            "2:5: expected end of line after the word, found \"X\""
+        );
+    }
+}
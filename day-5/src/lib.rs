@@ -0,0 +1,242 @@
+use aoc_core::generate::{Rng, SliceRandom, StdRng};
+use aoc_core::{numbered_lines, Answer, Generate, ParseError, ParseResult, Solution};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+pub struct Day5;
+
+/// The page ordering rules as `(before, after)` pairs, and the updates to check.
+#[derive(Debug)]
+pub struct PrintQueue {
+    pub rules_pairs: Vec<(i32, i32)>,
+    pub reports: Vec<Vec<i32>>,
+}
+
+impl Solution for Day5 {
+    type Input = PrintQueue;
+
+    /// The rules come first, then a blank line, then one comma-separated update per line.
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut lines = numbered_lines(input);
+
+        let mut rules_pairs = Vec::new();
+        for rule in lines.by_ref() {
+            if rule.is_blank() {
+                break;
+            }
+            let (parent, child) = rule.split_once("|")?;
+            rules_pairs.push((
+                parent.trim().parse::<i32>("a page number")?,
+                child.trim().parse::<i32>("a page number")?,
+            ));
+        }
+
+        let mut page_orders = Vec::new();
+        for report in lines.filter(|report| !report.is_blank()) {
+            let page_order = report
+                .split(",")
+                .map(|x| x.trim().parse::<i32>("a page number"))
+                .collect::<ParseResult<Vec<i32>>>()?;
+            page_orders.push(page_order);
+        }
+        if page_orders.is_empty() {
+            let line = input.lines().count() + 1;
+            return Err(ParseError::new(
+                line,
+                1,
+                "a blank line followed by updates",
+                "end of input",
+            ));
+        }
+
+        Ok(PrintQueue {
+            rules_pairs,
+            reports: page_orders,
+        })
+    }
+
+    /// Sums the middle page of every update that is already in the right order.
+    fn part1(queue: &Self::Input) -> Answer {
+        let rules = Rules::new(&queue.rules_pairs);
+        queue
+            .reports
+            .iter()
+            .filter(|page_order| rules.is_ordered(page_order))
+            .map(|page_order| middle_page(page_order))
+            .sum::<i32>()
+            .into()
+    }
+
+    /// Re-orders every update that breaks the rules and sums their middle pages.
+    fn part2(queue: &Self::Input) -> Answer {
+        let rules = Rules::new(&queue.rules_pairs);
+        queue
+            .reports
+            .iter()
+            .filter(|page_order| !rules.is_ordered(page_order))
+            .map(|page_order| {
+                let mut corrected = page_order.clone();
+                corrected.sort_by(|&a, &b| rules.compare(a, b));
+                middle_page(&corrected)
+            })
+            .sum::<i32>()
+            .into()
+    }
+}
+
+impl Generate for Day5 {
+    /// Picks a hidden page order and writes rules for a random share of its pairs, so the rules
+    /// have no cycles but often leave pages unordered. An update either follows the hidden order,
+    /// or is shuffled from pages the rules fully order among themselves, so every bad update has
+    /// exactly one correct ordering. Updates have an odd length.
+    fn generate(rng: &mut StdRng) -> String {
+        let mut pages: Vec<i32> = (10..100).collect();
+        pages.shuffle(rng);
+        pages.truncate(rng.random_range(1..15));
+
+        let density = rng.random_range(0.2..1.0);
+        let mut rules_pairs = Vec::new();
+        for (i, &before) in pages.iter().enumerate() {
+            for &after in &pages[i + 1..] {
+                if rng.random_bool(density) {
+                    rules_pairs.push((before, after));
+                }
+            }
+        }
+        rules_pairs.shuffle(rng);
+        let ruled: HashSet<(i32, i32)> = rules_pairs.iter().copied().collect();
+
+        let updates: Vec<String> = (0..rng.random_range(1..10))
+            .map(|_| {
+                let mut update = pages.clone();
+                update.shuffle(rng);
+                if rng.random_bool(0.5) {
+                    update.sort_by_key(|page| pages.iter().position(|p| p == page));
+                } else {
+                    let mut ordered: Vec<i32> = Vec::new();
+                    for page in update {
+                        if ordered.iter().all(|&other| {
+                            ruled.contains(&(other, page)) || ruled.contains(&(page, other))
+                        }) {
+                            ordered.push(page);
+                        }
+                    }
+                    update = ordered;
+                }
+                let keep = rng.random_range(0..update.len().div_ceil(2)) * 2 + 1;
+                let update: Vec<String> = update[..keep].iter().map(i32::to_string).collect();
+                update.join(",")
+            })
+            .collect();
+
+        let rules: Vec<String> = rules_pairs
+            .iter()
+            .map(|(before, after)| format!("{}|{}", before, after))
+            .collect();
+        format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n"))
+    }
+}
+
+/// The ordering rules, for looking up whether two pages have one between them.
+struct Rules(HashSet<(i32, i32)>);
+
+impl Rules {
+    fn new(rules_pairs: &[(i32, i32)]) -> Self {
+        Self(rules_pairs.iter().copied().collect())
+    }
+
+    /// Whether `after` coming after `before` breaks a rule.
+    fn breaks(&self, before: i32, after: i32) -> bool {
+        self.0.contains(&(after, before))
+    }
+
+    /// Whether no pair of pages in the update, near or far apart, breaks a rule.
+    fn is_ordered(&self, page_order: &[i32]) -> bool {
+        page_order.iter().enumerate().all(|(i, &before)| {
+            page_order[i + 1..]
+                .iter()
+                .all(|&after| !self.breaks(before, after))
+        })
+    }
+
+    /// Orders two pages by the rule between them; pages with no rule between them are equal.
+    fn compare(&self, a: i32, b: i32) -> Ordering {
+        if self.0.contains(&(a, b)) {
+            Ordering::Less
+        } else if self.0.contains(&(b, a)) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+/// The page in the middle of an update; updates are never empty.
+fn middle_page(page_order: &[i32]) -> i32 {
+    page_order[(page_order.len() - 1) / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::check_against_reference;
+
+    fn breaks_rule(rules: &[(i32, i32)], before: i32, after: i32) -> bool {
+        rules.contains(&(after, before))
+    }
+
+    /// Checks every pair of pages against the rules, and fixes bad updates by swapping
+    /// neighbours until no rule is broken.
+    fn reference(input: &str) -> (Answer, Answer) {
+        let queue = Day5::parse(input).unwrap();
+        let (mut part1, mut part2) = (0, 0);
+        for update in &queue.reports {
+            let ordered = (0..update.len()).all(|i| {
+                (i + 1..update.len())
+                    .all(|j| !breaks_rule(&queue.rules_pairs, update[i], update[j]))
+            });
+            if ordered {
+                part1 += update[update.len() / 2];
+                continue;
+            }
+
+            let mut fixed = update.clone();
+            let mut swapped = true;
+            while swapped {
+                swapped = false;
+                for i in 0..fixed.len() - 1 {
+                    if breaks_rule(&queue.rules_pairs, fixed[i], fixed[i + 1]) {
+                        fixed.swap(i, i + 1);
+                        swapped = true;
+                    }
+                }
+            }
+            part2 += fixed[fixed.len() / 2];
+        }
+        (part1.into(), part2.into())
+    }
+
+    #[test]
+    fn test_page_zero_is_an_ordinary_page() {
+        let queue = Day5::parse("5|0\n0|7\n\n5,0,7\n7,0,5\n").unwrap();
+        let rules = Rules::new(&queue.rules_pairs);
+
+        assert!(rules.is_ordered(&queue.reports[0]));
+        assert!(!rules.is_ordered(&queue.reports[1]));
+        assert_eq!(middle_page(&queue.reports[0]), 0);
+    }
+
+    #[test]
+    fn test_partial_order_is_stable() {
+        let queue = Day5::parse("1|3\n2|3\n\n2,1,3\n").unwrap();
+        for _ in 0..20 {
+            assert_eq!(Day5::part1(&queue), Answer::Number(1));
+            assert_eq!(Day5::part2(&queue), Answer::Number(0));
+        }
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day5>(100, reference);
+    }
+}
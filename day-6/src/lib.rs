@@ -0,0 +1,220 @@
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{Answer, Coord, Direction, Generate, Grid, ParseError, ParseResult, Solution};
+use std::collections::HashSet;
+
+/// The direction a guard character is facing, or `None` if the cell is not a guard.
+fn guard_direction(cell: char) -> Option<Direction> {
+    match cell {
+        '^' => Some(Direction::Up),
+        '>' => Some(Direction::Right),
+        'v' => Some(Direction::Down),
+        '<' => Some(Direction::Left),
+        _ => None,
+    }
+}
+
+// Only reads the board; no mutation.
+fn get_guard_locations(board: &Grid<char>) -> Vec<(Direction, Coord)> {
+    board
+        .iter()
+        .filter_map(|(coord, &cell)| guard_direction(cell).map(|guard| (guard, coord)))
+        .collect()
+}
+
+/// Where a guard went: every cell they stood on, in order, and whether they ended up walking in
+/// a loop rather than leaving the board.
+struct Route {
+    visited: Vec<Coord>,
+    looped: bool,
+}
+
+impl Route {
+    /// The distinct cells on the route.
+    fn cells(&self) -> Vec<Coord> {
+        let mut cells = self.visited.clone();
+        cells.sort();
+        cells.dedup();
+        cells
+    }
+}
+
+/// Walks a guard by the puzzle rules, remembering every (position, facing) state, until they
+/// leave the board or come back to a state they have been in before.
+fn walk(board: &Grid<char>, start: Coord, facing: Direction) -> Route {
+    let mut seen = HashSet::new();
+    let (mut position, mut facing) = (start, facing);
+    let mut visited = vec![start];
+    while seen.insert((position, facing)) {
+        match board.offset(position, facing) {
+            None => {
+                return Route {
+                    visited,
+                    looped: false,
+                }
+            }
+            Some(next) if board[next] == '#' => facing = facing.turn_right(),
+            Some(next) => {
+                position = next;
+                visited.push(next);
+            }
+        }
+    }
+    Route {
+        visited,
+        looped: true,
+    }
+}
+
+/// The guard's route across the board as given.
+fn guard_route(board: &Grid<char>) -> Route {
+    let (facing, start) = get_guard_locations(board)[0];
+    walk(board, start, facing)
+}
+
+pub struct Day6;
+
+impl Generate for Day6 {
+    /// A board with scattered obstacles and exactly one guard, who is guaranteed to walk off
+    /// the edge eventually.
+    fn generate(rng: &mut StdRng) -> String {
+        loop {
+            let (height, width) = (rng.random_range(1..20), rng.random_range(1..20));
+            let density = rng.random_range(0.05..0.3);
+            let mut board = Grid::new(height, width, '.');
+            for coord in board.coords().collect::<Vec<_>>() {
+                if rng.random_bool(density) {
+                    board[coord] = '#';
+                }
+            }
+
+            let start = Coord::new(rng.random_range(0..height), rng.random_range(0..width));
+            let guard = ['^', '>', 'v', '<'][rng.random_range(0..4)];
+            board[start] = guard;
+            if !walk(&board, start, guard_direction(guard).unwrap()).looped {
+                return board.to_string();
+            }
+        }
+    }
+}
+
+impl Solution for Day6 {
+    type Input = Grid<char>;
+
+    /// Reads the board, which must have exactly one guard on it.
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+        let board = Grid::parse_with(&lines.join("\n"), "'.', '#' or a guard", |cell| {
+            (matches!(cell, '.' | '#') || guard_direction(cell).is_some()).then_some(cell)
+        })?;
+
+        match get_guard_locations(&board)[..] {
+            [_] => Ok(board),
+            [] => Err(ParseError::new(
+                board.height() + 1,
+                1,
+                "a guard on the board",
+                "end of input",
+            )),
+            [_, (_, second), ..] => Err(ParseError::new(
+                second.row + 1,
+                second.col + 1,
+                "only one guard",
+                format!("{:?}", board[second]),
+            )),
+        }
+    }
+
+    /// Counts the positions the guard visits before leaving the board.
+    fn part1(board: &Self::Input) -> Answer {
+        guard_route(board).cells().len().into()
+    }
+
+    /// Counts the positions where a single new obstruction traps the guard in a loop. Only cells
+    /// on the guard's route can change it, and the guard's own cell can't be blocked.
+    fn part2(board: &Self::Input) -> Answer {
+        let (facing, start) = get_guard_locations(board)[0];
+        let mut blocked = board.clone();
+        guard_route(board)
+            .cells()
+            .into_iter()
+            .filter(|&coord| coord != start)
+            .filter(|&coord| {
+                blocked[coord] = '#';
+                let looped = walk(&blocked, start, facing).looped;
+                blocked[coord] = board[coord];
+                looped
+            })
+            .count()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::check_against_reference;
+
+    /// The cells a guard visits on the way off the board, or `None` if they are still on it
+    /// after more steps than there are (position, facing) states, so must be going round.
+    fn escape(board: &Grid<char>, start: Coord, facing: Direction) -> Option<Vec<Coord>> {
+        let (mut position, mut facing) = (start, facing);
+        let mut visited = vec![start];
+        for _ in 0..=4 * board.height() * board.width() {
+            match board.offset(position, facing) {
+                None => return Some(visited),
+                Some(next) if board[next] == '#' => facing = facing.turn_right(),
+                Some(next) => {
+                    position = next;
+                    visited.push(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Bounds the walk by the number of states instead of remembering them, and tries an
+    /// obstruction on every visited cell but the start on a fresh copy of the board.
+    fn reference(input: &str) -> (Answer, Answer) {
+        let board = Day6::parse(input).unwrap();
+        let (facing, start) = get_guard_locations(&board)[0];
+
+        let mut path = escape(&board, start, facing).unwrap();
+        path.sort();
+        path.dedup();
+
+        let loops = path
+            .iter()
+            .filter(|&&coord| coord != start)
+            .filter(|&&coord| {
+                let mut blocked = board.clone();
+                blocked[coord] = '#';
+                escape(&blocked, start, facing).is_none()
+            })
+            .count();
+        (path.len().into(), loops.into())
+    }
+
+    #[test]
+    fn test_board_needs_exactly_one_guard() {
+        assert_eq!(
+            Day6::parse("...\n.^.\n.^.").unwrap_err().to_string(),
+            "3:2: expected only one guard, found '^'"
+        );
+        assert_eq!(
+            Day6::parse("..#\n...").unwrap_err().to_string(),
+            "3:1: expected a guard on the board, found end of input"
+        );
+    }
+
+    #[test]
+    fn test_trapped_guard_stops() {
+        let board = Day6::parse(".#.\n#^#\n.#.").unwrap();
+
+        assert_eq!(Day6::part1(&board), Answer::Number(1));
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day6>(200, reference);
+    }
+}
@@ -0,0 +1,202 @@
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{numbered_lines, Answer, Generate, ParseResult, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(i64, Vec<i64>)>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        extract_totals_and_numbers(input)
+    }
+
+    /// Sums the targets that can be made from their operands with `+` and `*`.
+    fn part1(equations: &Self::Input) -> Answer {
+        sum_possible_totals(equations, 2).into()
+    }
+
+    /// Sums the targets that can be made with `+`, `*` and `||` concatenation.
+    fn part2(equations: &Self::Input) -> Answer {
+        sum_possible_totals(equations, 3).into()
+    }
+}
+
+impl Generate for Day7 {
+    /// Builds each target by applying random operators to its operands, then knocks some
+    /// targets off by a little so not every equation can be solved.
+    fn generate(rng: &mut StdRng) -> String {
+        (0..rng.random_range(1..30))
+            .map(|_| {
+                let operands: Vec<i64> = (0..rng.random_range(1..7))
+                    .map(|_| rng.random_range(1..100))
+                    .collect();
+                let mut total = operands[0];
+                for &operand in &operands[1..] {
+                    total = match rng.random_range(0..3) {
+                        0 => total + operand,
+                        1 => total * operand,
+                        _ => format!("{}{}", total, operand).parse().unwrap(),
+                    };
+                }
+                if rng.random_bool(0.3) {
+                    total += rng.random_range(1..10);
+                }
+                let operands: Vec<String> = operands.iter().map(i64::to_string).collect();
+                format!("{}: {}\n", total, operands.join(" "))
+            })
+            .collect()
+    }
+}
+
+/// Splits each `total: a b c` line into its target and operands.
+pub fn extract_totals_and_numbers(contents: &str) -> ParseResult<Vec<(i64, Vec<i64>)>> {
+    let mut extracted_totals_and_numbers = Vec::new();
+    for s in numbered_lines(contents).filter(|s| !s.is_blank()) {
+        let (total, numbers) = s.split_once(":")?;
+        let total = total.trim().parse::<i64>("the test value")?;
+        let data = numbers
+            .fields()
+            .map(|num| num.parse::<i64>("an operand"))
+            .collect::<ParseResult<Vec<i64>>>()?;
+        extracted_totals_and_numbers.push((total, data));
+    }
+    Ok(extracted_totals_and_numbers)
+}
+
+/// Sums in an `i128`, so many large targets can't overflow the total.
+fn sum_possible_totals(equations: &[(i64, Vec<i64>)], operator_count: usize) -> i128 {
+    equations
+        .iter()
+        .filter(|(total, data)| get_is_product_possible(data, *total, operator_count))
+        .map(|&(total, _)| total as i128)
+        .sum()
+}
+
+/// An operator that can go between two operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Multiply,
+    /// `||`, writing the right operand's digits after the left's.
+    Concatenate,
+}
+
+const OPERATORS: [Operator; 3] = [Operator::Add, Operator::Multiply, Operator::Concatenate];
+
+impl Operator {
+    /// The result, or `None` if it doesn't fit in an `i64` or a negative number would be
+    /// concatenated onto the end.
+    fn apply(self, left: i64, right: i64) -> Option<i64> {
+        match self {
+            Operator::Add => left.checked_add(right),
+            Operator::Multiply => left.checked_mul(right),
+            Operator::Concatenate => {
+                let digits = right.checked_ilog10().map_or(1, |log| log + 1);
+                let shifted = left.checked_mul(10i64.checked_pow(digits)?)?;
+                if right < 0 {
+                    None
+                } else if left < 0 {
+                    shifted.checked_sub(right)
+                } else {
+                    shifted.checked_add(right)
+                }
+            }
+        }
+    }
+}
+
+/// Tries every combination of the first `operator_count` operators (`+`, `*`, `||`) between
+/// the operands, left to right. A combination that overflows along the way can't make the
+/// total.
+pub fn get_is_product_possible(data: &[i64], total: i64, operator_count: usize) -> bool {
+    let Some((&first, rest)) = data.split_first() else {
+        return false;
+    };
+    let operators = &OPERATORS[..operator_count];
+    let total_combinations = operator_count.pow(rest.len() as u32);
+
+    (0..total_combinations).any(|i| {
+        let result = rest
+            .iter()
+            .enumerate()
+            .try_fold(first, |running, (j, &operand)| {
+                let op = operators[(i / operator_count.pow(j as u32)) % operator_count];
+                op.apply(running, operand)
+            });
+        result == Some(total)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::check_against_reference;
+
+    /// Tries both (or all three) operators on the running total, recursively.
+    fn solvable(total: i64, running: i64, rest: &[i64], concatenation: bool) -> bool {
+        let Some((&next, rest)) = rest.split_first() else {
+            return running == total;
+        };
+        solvable(total, running + next, rest, concatenation)
+            || solvable(total, running * next, rest, concatenation)
+            || (concatenation
+                && solvable(
+                    total,
+                    format!("{}{}", running, next).parse().unwrap(),
+                    rest,
+                    concatenation,
+                ))
+    }
+
+    fn reference(input: &str) -> (Answer, Answer) {
+        let equations = extract_totals_and_numbers(input).unwrap();
+        let sum = |concatenation| {
+            equations
+                .iter()
+                .filter(|(total, data)| solvable(*total, data[0], &data[1..], concatenation))
+                .map(|(total, _)| total)
+                .sum::<i64>()
+        };
+        (sum(false).into(), sum(true).into())
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day7>(100, reference);
+    }
+
+    #[test]
+    fn test_extract_totals_and_numbers() {
+        let equations = extract_totals_and_numbers("190: 10 19\n3267: 81 40 27\n").unwrap();
+        assert_eq!(
+            equations,
+            vec![(190, vec![10, 19]), (3267, vec![81, 40, 27])]
+        );
+    }
+
+    #[test]
+    fn test_get_is_product_possible_with_two_operators() {
+        assert!(get_is_product_possible(&[10, 19], 190, 2));
+        assert!(get_is_product_possible(&[81, 40, 27], 3267, 2));
+        assert!(!get_is_product_possible(&[15, 6], 156, 2));
+    }
+
+    #[test]
+    fn test_overflow_is_not_a_match() {
+        assert!(!get_is_product_possible(&[99999999999, 99999999999], 1, 3));
+        assert!(get_is_product_possible(&[i64::MAX, 1], i64::MAX, 3));
+        assert_eq!(Operator::Concatenate.apply(-12, 3), Some(-123));
+        assert_eq!(Operator::Concatenate.apply(12, 0), Some(120));
+        assert_eq!(
+            Day7::part2(&Day7::parse("1: 99999999999 99999999999\n").unwrap()),
+            Answer::Number(0)
+        );
+    }
+
+    #[test]
+    fn test_get_is_product_possible_with_concatenation() {
+        assert!(get_is_product_possible(&[15, 6], 156, 3));
+        assert!(get_is_product_possible(&[6, 8, 6, 15], 7290, 3));
+        assert!(!get_is_product_possible(&[9, 7, 18, 13], 21037, 3));
+    }
+}
@@ -0,0 +1,154 @@
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{Answer, Coord, Generate, Grid, ParseResult, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let lines: Vec<&str> = input.lines().map(str::trim).collect();
+        Grid::parse(&lines.join("\n"))
+    }
+
+    /// Counts the antinodes exactly one antenna-spacing beyond each pair of matching antennas.
+    fn part1(columns: &Self::Input) -> Answer {
+        count_antinodes(columns, false).into()
+    }
+
+    /// Counts every in-line antinode, including resonant harmonics and the antennas themselves.
+    fn part2(columns: &Self::Input) -> Answer {
+        count_antinodes(columns, true).into()
+    }
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl Generate for Day8 {
+    /// A map with a handful of antennas spread over a few frequencies.
+    fn generate(rng: &mut StdRng) -> String {
+        let (height, width) = (rng.random_range(1..15), rng.random_range(1..15));
+        let frequencies = &['a', 'A', '0', 'z'][..rng.random_range(1..=4)];
+        let mut map = Grid::new(height, width, '.');
+        for _ in 0..rng.random_range(0..12) {
+            let coord = Coord::new(rng.random_range(0..height), rng.random_range(0..width));
+            map[coord] = frequencies[rng.random_range(0..frequencies.len())];
+        }
+        map.to_string()
+    }
+}
+
+fn count_antinodes(grid: &Grid<char>, resonant_harmonics: bool) -> usize {
+    // craete an object with a key value of a char and an array of coords
+    // append the extra vector where the char key already exists
+    let mut char_coords: HashMap<char, Vec<Coord>> = HashMap::new();
+    for (coord, &c) in grid.iter() {
+        if c != '.' {
+            char_coords.entry(c).or_default().push(coord);
+        }
+    }
+
+    // reduce over the hashmap and get the differences in x-y space between all the coords
+    // + the differences and minus the differences.
+    // for each difference that is within the map boundary add 1 to the reduce total
+    let mut seen = HashSet::new();
+    for coords in char_coords.values() {
+        if coords.len() == 1 {
+            continue;
+        }
+
+        if resonant_harmonics {
+            seen.extend(coords.iter().copied());
+        }
+
+        for (i, &first) in coords.iter().enumerate() {
+            for (j, &second) in coords.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+
+                let row_step = second.row as isize - first.row as isize;
+                let col_step = second.col as isize - first.col as isize;
+
+                if resonant_harmonics {
+                    // every grid point on the line counts, so walk it one whole cell at a time
+                    let divisor = gcd(row_step.abs(), col_step.abs());
+                    let mut anti_node = first;
+                    while let Some(next) =
+                        grid.offset_by(anti_node, row_step / divisor, col_step / divisor)
+                    {
+                        seen.insert(next);
+                        anti_node = next;
+                    }
+                    continue;
+                }
+
+                // one antenna twice as far away as the other: either beyond `second`, or a
+                // third of the way back towards `first` when that lands on a grid point
+                if let Some(beyond) = grid.offset_by(second, row_step, col_step) {
+                    seen.insert(beyond);
+                }
+                if row_step % 3 == 0 && col_step % 3 == 0 {
+                    if let Some(between) = grid.offset_by(second, -row_step / 3, -col_step / 3) {
+                        seen.insert(between);
+                    }
+                }
+            }
+        }
+    }
+
+    seen.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::check_against_reference;
+
+    /// Asks of every cell whether some pair of same-frequency antennas makes it an antinode.
+    fn reference(input: &str) -> (Answer, Answer) {
+        let map = Day8::parse(input).unwrap();
+        let antennas: Vec<(Coord, char)> = map
+            .iter()
+            .filter(|(_, &c)| c != '.')
+            .map(|(coord, &c)| (coord, c))
+            .collect();
+        let position = |coord: Coord| (coord.row as i64, coord.col as i64);
+
+        let (mut part1, mut part2) = (0, 0);
+        for cell in map.coords() {
+            let (row, col) = position(cell);
+            let pairs = antennas
+                .iter()
+                .flat_map(|a| antennas.iter().map(move |b| (a, b)));
+            let mut is_antinode = false;
+            let mut on_line = false;
+            for ((a, freq_a), (b, freq_b)) in pairs {
+                if a == b || freq_a != freq_b {
+                    continue;
+                }
+                let ((ra, ca), (rb, cb)) = (position(*a), position(*b));
+                let in_line = (rb - ra) * (col - ca) == (cb - ca) * (row - ra);
+                let squared_distance = |r: i64, c: i64| (row - r).pow(2) + (col - c).pow(2);
+                // in line, with `a` exactly twice as far away as `b`
+                is_antinode |= in_line && squared_distance(ra, ca) == 4 * squared_distance(rb, cb);
+                on_line |= in_line;
+            }
+            part1 += is_antinode as usize;
+            part2 += on_line as usize;
+        }
+        (part1.into(), part2.into())
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day8>(100, reference);
+    }
+}
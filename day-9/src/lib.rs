@@ -1,20 +1,54 @@
-use std::{fs, vec};
-
-fn parse_data_from_file(file_name: &str) -> Vec<String> {
-    fs::read_to_string(file_name)
-        .expect("Failed to read file")
-        .lines()
-        .map(|line| line.to_string())
-        .collect()
+use aoc_core::generate::{Rng, StdRng};
+use aoc_core::{numbered_lines, Answer, Generate, ParseError, ParseResult, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<u32>;
+
+    /// Reads the dense disk map: alternating file and free-space lengths, one digit each.
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let data_string = numbered_lines(input)
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "a disk map", "end of input"))?
+            .trim();
+        data_string
+            .chars()
+            .map(|(c, span)| c.to_digit(10).ok_or_else(|| span.error("a digit")))
+            .collect()
+    }
+
+    /// Compacts the disk one block at a time and returns the filesystem checksum.
+    fn part1(disk_map: &Self::Input) -> Answer {
+        compact_blocks(disk_map).into()
+    }
+
+    /// Compacts the disk by moving whole files and returns the filesystem checksum.
+    fn part2(disk_map: &Self::Input) -> Answer {
+        do_part_two(disk_map).into()
+    }
 }
 
-fn main() {
-    let data_string = parse_data_from_file("src/data.txt")[0].clone();
-    // let data_string = parse_data_from_file("src/example.txt")[0].clone();
+impl Generate for Day9 {
+    /// A dense disk map. Files are 1-9 blocks long and the gaps between them 0-9.
+    fn generate(rng: &mut StdRng) -> String {
+        let files = rng.random_range(1..25);
+        let mut disk_map = String::new();
+        for file in 0..files {
+            if file > 0 {
+                disk_map += &rng.random_range(0..10).to_string();
+            }
+            disk_map += &rng.random_range(1..10).to_string();
+        }
+        disk_map + "\n"
+    }
+}
+
+fn compact_blocks(disk_map: &[u32]) -> i64 {
     let mut number_data: Vec<i32> = vec![];
 
-    data_string.chars().enumerate().for_each(|(i, c)| {
-        let count = c.to_digit(10).unwrap() as usize;
+    disk_map.iter().enumerate().for_each(|(i, &count)| {
+        let count = count as usize;
         if i & 1 == 0 {
             let number = (i / 2) as i32;
             number_data.extend(vec![number; count]);
@@ -47,11 +81,7 @@ fn main() {
         number_data.swap(start_pointer, end_pointer);
     }
 
-    let check_sum = calculate_checksum(&number_data);
-
-    println!("The check sum is {:?}", check_sum);
-
-    do_part_two(data_string);
+    calculate_checksum(&number_data)
 }
 
 #[derive(Debug)]
@@ -69,13 +99,12 @@ struct FreeSpace {
 
 // priority queue
 
-fn do_part_two(data_string: String) {
+fn do_part_two(disk_map: &[u32]) -> i64 {
     let mut file_data: Vec<FileData> = Vec::new();
     let mut free_space_data: Vec<FreeSpace> = Vec::new();
     let mut current_start = 0;
-    data_string.chars().enumerate().for_each(|(i, c)| {
+    disk_map.iter().enumerate().for_each(|(i, &length)| {
         if i % 2 == 0 {
-            let length = c.to_digit(10).unwrap();
             let id = (i / 2) as isize;
             file_data.push(FileData {
                 id,
@@ -84,7 +113,7 @@ fn do_part_two(data_string: String) {
             });
             current_start += length as usize;
         } else {
-            let free_space = c.to_digit(10).unwrap();
+            let free_space = length;
             free_space_data.push(FreeSpace {
                 length: free_space,
                 start: current_start,
@@ -92,8 +121,6 @@ fn do_part_two(data_string: String) {
             current_start += free_space as usize;
         }
     });
-    // access file data
-    println!("free_space_data: {:?}", free_space_data);
 
     // reverse file_data
 
@@ -121,7 +148,6 @@ fn do_part_two(data_string: String) {
         }
     });
     // file_data.reverse();
-    println!("file_data: {:?}", file_data);
 
     // the check some is the start position multiplied by the id for each
     // file in the file_data remembering to add one to the index for each file greater than 1
@@ -134,7 +160,7 @@ fn do_part_two(data_string: String) {
     //         .collect::<Vec<i32>>(),
     // );
 
-    println!("The check sum is {:?}", check_sum);
+    check_sum
 }
 // is it better to hash the free space as vectors for it's size
 // a hash of free space size and then a mutable vector of the start index.
@@ -159,7 +185,7 @@ fn calculate_checksum(data: &[i32]) -> i64 {
 // iterate over the file data and for each
 
 fn calcualte_file_checksum(data: &[FileData]) -> i64 {
-    data.iter().enumerate().fold(0i64, |acc, (index, file)| {
+    data.iter().enumerate().fold(0i64, |acc, (_index, file)| {
         // println!("index {:?} s {:?}, {:?}", index, s, acc);
         let mut fileacc = 0;
         for i in 0..file.length {
@@ -168,3 +194,64 @@ fn calcualte_file_checksum(data: &[FileData]) -> i64 {
         acc + fileacc
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::check_against_reference;
+
+    /// Lays the disk out block by block, with `None` for free space.
+    fn blocks(disk_map: &[u32]) -> Vec<Option<usize>> {
+        disk_map
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &length)| {
+                let id = (i % 2 == 0).then_some(i / 2);
+                std::iter::repeat_n(id, length as usize)
+            })
+            .collect()
+    }
+
+    fn checksum(blocks: &[Option<usize>]) -> i64 {
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (position * id.unwrap_or(0)) as i64)
+            .sum()
+    }
+
+    /// Moves blocks and files on the full block layout, one step at a time.
+    fn reference(input: &str) -> (Answer, Answer) {
+        let disk_map = Day9::parse(input).unwrap();
+
+        let mut disk = blocks(&disk_map);
+        loop {
+            while disk.last() == Some(&None) {
+                disk.pop();
+            }
+            let Some(gap) = disk.iter().position(Option::is_none) else {
+                break;
+            };
+            disk[gap] = disk.pop().unwrap();
+        }
+        let part1 = checksum(&disk);
+
+        let mut disk = blocks(&disk_map);
+        for id in (0..disk_map.len().div_ceil(2)).rev() {
+            let start = disk.iter().position(|&block| block == Some(id)).unwrap();
+            let length = disk_map[id * 2] as usize;
+            let gap = (0..start).find(|&gap| disk[gap..gap + length].iter().all(Option::is_none));
+            if let Some(gap) = gap {
+                for offset in 0..length {
+                    disk.swap(gap + offset, start + offset);
+                }
+            }
+        }
+        (part1.into(), checksum(&disk).into())
+    }
+
+    #[test]
+    fn test_matches_reference_on_generated_inputs() {
+        check_against_reference::<Day9>(100, reference);
+    }
+}